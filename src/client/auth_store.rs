@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write as _};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt as _;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Auth;
+
+/// A persistent storage for authentication credentials.
+///
+/// Once passed to [`Builder::auth_store()`][crate::client::Builder::auth_store()], the store is
+/// invoked every time the client's authentication state changes: credentials are saved after
+/// logging in, refreshing tokens, or calling [`Client::set_auth()`][crate::Client::set_auth()],
+/// and cleared after logging out or when the server sends an invalid token. This way, a rotated
+/// refresh token is never lost, even if the program exits unexpectedly.
+///
+/// The methods are called while the authentication state is locked, so they should return
+/// quickly. Errors returned by [`save()`][AuthStore::save()] and [`clear()`][AuthStore::clear()]
+/// don't fail the operation that caused the state change; they are logged instead.
+pub trait AuthStore: Debug + Send + Sync {
+    /// Loads the stored credentials, returning `None` if there are none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the credentials cannot be read.
+    fn load(&self) -> io::Result<Option<Auth>>;
+
+    /// Saves the credentials, replacing the previously stored ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the credentials cannot be written.
+    fn save(&self, auth: &Auth) -> io::Result<()>;
+
+    /// Removes the stored credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the credentials cannot be removed.
+    fn clear(&self) -> io::Result<()>;
+}
+
+// Has the same layout as `Auth` with the `serde` feature enabled, so files written by either are
+// interchangeable
+#[derive(Deserialize, Serialize)]
+struct StoredAuth {
+    access_token: String,
+    refresh_token: String,
+}
+
+/// An [`AuthStore`] that keeps the credentials in a JSON file.
+///
+/// The file is written to a temporary file first and then renamed, so it's never left
+/// half-written. On Unix, the file is only readable and writable by its owner. A missing file
+/// means there are no stored credentials.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bonfire::{ClientBuilder, Result};
+/// use bonfire::client::JsonFileStore;
+///
+/// # fn main() -> Result<()> {
+/// let client = &ClientBuilder::new()
+///     .auth_store(JsonFileStore::new("credentials.json"))?
+///     .build();
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsonFileStore {
+    path: PathBuf,
+}
+impl JsonFileStore {
+    /// Creates a new `JsonFileStore` that keeps the credentials at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl AuthStore for JsonFileStore {
+    fn load(&self) -> io::Result<Option<Auth>> {
+        let data = match fs::read(&self.path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        let stored = serde_json::from_slice::<StoredAuth>(&data)?;
+        Ok(Some(Auth {
+            access_token: stored.access_token,
+            refresh_token: stored.refresh_token,
+        }))
+    }
+
+    fn save(&self, auth: &Auth) -> io::Result<()> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let data = serde_json::to_vec(&StoredAuth {
+            access_token: auth.access_token.clone(),
+            refresh_token: auth.refresh_token.clone(),
+        })?;
+
+        // A unique name keeps concurrent saves (even from other processes) from writing to the
+        // same temporary file
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(format!(
+            ".{}.{}.tmp",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let result = options
+            .open(&temp_path)
+            .and_then(|mut file| file.write_all(&data))
+            .and_then(|()| fs::rename(&temp_path, &self.path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
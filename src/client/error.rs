@@ -2,13 +2,16 @@ use std::error::Error as StdError;
 use std::fmt::Debug;
 use std::result::Result as StdResult;
 use std::sync::Arc;
+use std::time::Duration;
 
 use http::StatusCode;
 use thiserror::Error;
 
+#[cfg(feature = "cassette")]
+use crate::client::CassetteError;
 #[cfg(feature = "fcm")]
 use crate::client::FcmError;
-use crate::client::{JwtError, RequestError};
+use crate::client::{JwtError, RequestError, TimeoutPhase};
 use crate::{MeliorError, RootError};
 
 /// A type alias for [`Result<T, Error>`][StdResult].
@@ -29,6 +32,15 @@ pub enum Error {
     /// The provided attachment exceeds the maximum size the server can process
     #[error("attachment is too large")]
     AttachmentTooLarge,
+    /// An error occurred while loading credentials from an
+    /// [`AuthStore`][crate::client::AuthStore]
+    #[error("auth store error")]
+    AuthStoreError(#[source] std::io::Error),
+    /// An error occurred while recording or replaying a cassette
+    #[cfg(feature = "cassette")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cassette")))]
+    #[error("cassette error")]
+    CassetteError(#[from] CassetteError),
     /// An error occurred while converting the response's raw data into models or vice-versa
     #[error("{0}")]
     ConversionError(String),
@@ -63,6 +75,13 @@ pub enum Error {
     /// The Melior server returned an error
     #[error("melior server error")]
     MeliorError(#[source] MeliorError),
+    /// The request doesn't fit into the rate limiter's quota. Only returned by a
+    /// [`RateLimiter`][crate::client::RateLimiter] in the non-blocking mode
+    #[error("rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        /// The time to wait before the request can be sent
+        retry_after: Duration,
+    },
     /// A request-specific error
     #[error("request-specific error")]
     RequestError(#[source] Box<dyn StdError + Send + Sync + 'static>),
@@ -72,6 +91,15 @@ pub enum Error {
     /// The Root server returned an error
     #[error("root server error")]
     RootError(#[source] RootError),
+    /// The request didn't complete within the configured timeout
+    #[error("{phase} timed out")]
+    Timeout {
+        /// The phase of the request that took too long
+        phase: TimeoutPhase,
+    },
+    /// An error occurred within a custom [`Transport`][crate::client::Transport]
+    #[error("transport error")]
+    TransportError(#[source] Box<dyn StdError + Send + Sync + 'static>),
     /// The client is unauthenticated
     #[error("unauthenticated client")]
     Unauthenticated,
@@ -95,6 +123,22 @@ pub enum Error {
     UnsuccessfulResponse(StatusCode),
 }
 impl Error {
+    // Whether the server has rejected the access token the request was sent with. Neither server
+    // documents how it does that: none of the Root error codes (see `RawRootError`) stands for a
+    // rejected token, so a `401 Unauthorized` status is assumed, and the Melior server is assumed
+    // to use the `TokenExpired` code it returns for expired refresh tokens (see `RefreshError`).
+    // Only checked for requests that carried an access token, and only once per request, so a
+    // wrong guess costs a single refresh
+    pub(super) fn is_token_rejected(&self) -> bool {
+        match self {
+            Error::UnsuccessfulResponse(status) => *status == StatusCode::UNAUTHORIZED,
+            Error::MeliorError(error) => {
+                matches!(error.message.split_once(':'), Some(("TokenExpired", _)))
+            }
+            _ => false,
+        }
+    }
+
     pub(super) fn try_from_root<E: RequestError>(root_error: RootError) -> Result<Error>
     where
         for<'a> &'a E::Source: From<&'a RootError>,
@@ -1,41 +1,57 @@
+mod auth_store;
 mod builder;
+#[cfg(feature = "cassette")]
+mod cassette;
 mod error;
 mod graphql;
 mod jwt;
+mod proxy;
+mod rate_limiter;
 mod request;
+mod retry;
 mod service;
 mod token_provider;
+mod transport;
 
 use std::sync::Arc;
+use std::time::Duration;
 
+pub use auth_store::{AuthStore, JsonFileStore};
 pub use builder::Builder;
-use bytes::Bytes;
+#[cfg(feature = "cassette")]
+pub use cassette::{Error as CassetteError, RecordingTransport, ReplayTransport};
 pub use error::{Error, Result};
-use governor::clock::DefaultClock;
-use governor::state::{InMemoryState, NotKeyed};
-use governor::{Quota, RateLimiter};
+use futures::Stream;
 use http::{HeaderMap, Uri, header};
-use http_body_util::{Either, Empty, Full};
-use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
-use hyper_util::client::legacy::connect::HttpConnector;
-use hyper_util::rt::TokioExecutor;
 pub use jwt::Error as JwtError;
+pub use proxy::Proxy;
+pub use rate_limiter::RateLimiter;
+use rate_limiter::Server;
 pub(crate) use request::{
     EmptyResponse, InfallibleRequest, Request, RequestError, RequestErrorSource,
 };
+pub use retry::RetryPolicy;
 #[cfg(feature = "fcm")]
 use service::FcmService;
 #[cfg(feature = "fcm")]
-pub use service::fcm::Error as FcmError;
-use service::{MeliorService, RootService};
+pub use service::fcm::{Error as FcmError, Listener as PushListener};
+use service::{Dispatcher, MeliorService, RootService};
 use token_provider::TokenProvider;
 use tracing::instrument;
+use transport::TimedTransport;
+pub use transport::{HyperTransport, MemoryTransport, TimeoutPhase, Transport};
 
+use crate::models::auth::{LinkedLogins, OAuthGrant, Session};
+use crate::models::streams::auto_paginated_stream;
 use crate::models::{Auth, FirebaseConfig, InitialData};
 #[cfg(feature = "fcm")]
 use crate::models::{FcmAndroidRegistration, FcmCredentials};
-use crate::queries::auth::{LoginEmailQuery, LogoutQuery};
-use crate::requests::other::BootstrapRequest;
+use crate::queries::auth::{
+    AccountSecurityQuery, ChangePasswordQuery, ListOAuthGrantsQuery, LoginEmailQuery, LogoutQuery,
+    RegisterEmailQuery, ResendVerificationQuery, SendPasswordRecoveryQuery,
+};
+use crate::queries::other::GraphqlQuery;
+use crate::requests::other::{BootstrapRequest, RootCallRequest};
 use crate::{MeliorError, MeliorQuery, RootError, RootRequest};
 
 // Some requests require this value and return various responses depending on it
@@ -44,20 +60,17 @@ const API_VERSION: &str = "3.1.0";
 // The maximum allowed size for any type of attachment (6 MiB)
 const ATTACHMENT_MAX_SIZE: usize = 6 * 1024 * 1024;
 
-type HyperClient = hyper_util::client::legacy::Client<
-    HttpsConnector<HttpConnector>,
-    Either<Full<Bytes>, Empty<Bytes>>,
->;
-
 #[derive(Debug)]
 struct Inner {
-    hyper_client: HyperClient,
+    transport: Arc<dyn Transport>,
     root_service: RootService,
     melior_service: MeliorService,
     #[cfg(feature = "fcm")]
     fcm_service: FcmService,
     token_provider: TokenProvider,
-    rate_limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
 }
 
 /// An asynchronous, thread-safe HTTP client for the Bonfire API.
@@ -85,34 +98,49 @@ struct Inner {
 #[derive(Clone, Debug)]
 pub struct Client {
     inner: Arc<Inner>,
+    // Overrides the request timeout for this handle. See `Client::with_timeout()`
+    request_timeout: Option<Duration>,
 }
 impl Client {
+    #[expect(clippy::too_many_arguments)]
     fn new(
         root_uri: Uri,
         melior_uri: Uri,
-        auth: Option<Auth>,
-        quota: Quota,
+        transport: Arc<dyn Transport>,
+        token_provider: TokenProvider,
+        rate_limiter: RateLimiter,
         firebase_config: FirebaseConfig,
+        proxy: Option<Proxy>,
+        background_refresh: bool,
+        retry_policy: RetryPolicy,
+        request_timeout: Option<Duration>,
     ) -> Self {
-        let connector = HttpsConnectorBuilder::new()
-            .with_webpki_roots()
-            .https_or_http()
-            .enable_all_versions()
-            .build();
+        // Only push listeners need the proxy, since the transport already has it
+        #[cfg(not(feature = "fcm"))]
+        drop(proxy);
 
-        Self {
+        let client = Self {
             inner: Arc::new(Inner {
-                hyper_client: hyper_util::client::legacy::Client::builder(TokioExecutor::new())
-                    .build(connector),
+                transport,
                 root_service: RootService::new(root_uri),
                 melior_service: MeliorService::new(melior_uri),
                 #[cfg(feature = "fcm")]
-                fcm_service: FcmService::new(firebase_config),
-                // This error was previously caught in Builder::auth()
-                token_provider: TokenProvider::new(auth).expect("failed to create TokenProvider"),
-                rate_limiter: RateLimiter::direct(quota),
+                fcm_service: FcmService::new(firebase_config, proxy),
+                token_provider,
+                rate_limiter,
+                retry_policy,
+                request_timeout,
             }),
+            request_timeout: None,
+        };
+
+        if background_refresh {
+            client
+                .inner
+                .token_provider
+                .spawn_background_refresh(&client);
         }
+        client
     }
 
     /// Checks if the client is currently authenticated.
@@ -160,7 +188,8 @@ impl Client {
     /// * Returns [`LoginError::HardBanned`][crate::models::auth::LoginError::HardBanned] if the
     ///   account is permanently banned.
     /// * Returns [`LoginError::TfaRequired`][crate::models::auth::LoginError::TfaRequired] if
-    ///   Two-Factor Authentication (TFA) is required to complete the login process.
+    ///   Two-Factor Authentication (TFA) is required to complete the login process. Such logins
+    ///   can't be completed with this client yet.
     /// * Returns [`Error`] if any other error occurs while sending the login request.
     ///
     /// # Examples
@@ -190,6 +219,98 @@ impl Client {
         Ok(self)
     }
 
+    /// Registers a new account on Bonfire using email and password and logs the client into it.
+    ///
+    /// The email address of the new account must be verified through the link sent to it. If the
+    /// email doesn't arrive, it can be sent again with
+    /// [`Client::resend_verification`][crate::Client::resend_verification].
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::AlreadyAuthenticated`] if the client is already authenticated. Call
+    ///   [`Client::logout`][crate::Client::logout] to terminate the current session before
+    ///   registering.
+    /// * Returns [`RegisterError::InvalidEmail`][crate::models::auth::RegisterError::InvalidEmail]
+    ///   if the provided email address is invalid.
+    /// * Returns [`RegisterError::EmailTaken`][crate::models::auth::RegisterError::EmailTaken] if
+    ///   the provided email address is already registered.
+    /// * Returns [`RegisterError::WeakPassword`][crate::models::auth::RegisterError::WeakPassword]
+    ///   if the provided password doesn't meet the server's requirements.
+    /// * Returns [`Error`] if any other error occurs while sending the registration request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let client = &Client::default();
+    /// client.register("email", "password").await?;
+    /// // You can now send requests using `client` as the newly registered user
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn register(&self, email: &str, password: &str) -> Result<&Self> {
+        if self.inner.token_provider.is_auth().await {
+            return Err(Error::AlreadyAuthenticated);
+        }
+
+        let auth = Auth::from(
+            RegisterEmailQuery::new(email, password)
+                .send_request(self)
+                .await?,
+        );
+        self.inner.token_provider.set_auth(Some(auth)).await?;
+        Ok(self)
+    }
+
+    /// Sends the email address verification link again.
+    ///
+    /// This method doesn't require the client to be authenticated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns
+    ///   [`ResendVerificationError::WrongEmail`][crate::models::auth::ResendVerificationError::WrongEmail]
+    ///   if the provided email address is not registered.
+    /// * Returns
+    ///   [`ResendVerificationError::AlreadyVerified`][crate::models::auth::ResendVerificationError::AlreadyVerified]
+    ///   if the email address has already been verified.
+    /// * Returns
+    ///   [`ResendVerificationError::TryAgainLater`][crate::models::auth::ResendVerificationError::TryAgainLater]
+    ///   if a verification email was sent too recently.
+    /// * Returns [`Error`] if any other error occurs while sending the request.
+    pub async fn resend_verification(&self, email: &str) -> Result<()> {
+        ResendVerificationQuery::new(email)
+            .send_request(self)
+            .await?;
+        Ok(())
+    }
+
+    /// Sends a link to reset the password of the account registered with the given email address.
+    ///
+    /// This method doesn't require the client to be authenticated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns
+    ///   [`PasswordRecoveryError::InvalidEmail`][crate::models::auth::PasswordRecoveryError::InvalidEmail]
+    ///   if the provided email address is invalid.
+    /// * Returns
+    ///   [`PasswordRecoveryError::WrongEmail`][crate::models::auth::PasswordRecoveryError::WrongEmail]
+    ///   if the provided email address is not registered.
+    /// * Returns
+    ///   [`PasswordRecoveryError::TryAgainLater`][crate::models::auth::PasswordRecoveryError::TryAgainLater]
+    ///   if a password recovery email was sent too recently.
+    /// * Returns [`Error`] if any other error occurs while sending the request.
+    pub async fn send_password_recovery(&self, email: &str) -> Result<()> {
+        SendPasswordRecoveryQuery::new(email)
+            .send_request(self)
+            .await?;
+        Ok(())
+    }
+
     /// Logs the client out, invalidating the current authentication session.
     ///
     /// After logging out, the client becomes unauthenticated but can still send requests that do
@@ -228,13 +349,116 @@ impl Client {
         Ok(self)
     }
 
+    /// Changes the password of the current user.
+    ///
+    /// The server doesn't return new credentials when the password is changed, so the current ones
+    /// are refreshed right after, and the refreshed credentials replace them. Other sessions may be
+    /// terminated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Unauthenticated`] if the client is not authenticated.
+    /// * Returns
+    ///   [`ChangePasswordError::WrongPassword`][crate::models::auth::ChangePasswordError::WrongPassword]
+    ///   if the provided old password is incorrect.
+    /// * Returns
+    ///   [`ChangePasswordError::WeakPassword`][crate::models::auth::ChangePasswordError::WeakPassword]
+    ///   if the provided new password doesn't meet the server's requirements.
+    /// * Returns [`Error::JwtError`] if the server returns a malformed token.
+    /// * Returns [`Error`] if any other error occurs while sending the request. If refreshing the
+    ///   credentials fails, the password has been changed nonetheless.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #     let client = &Client::default();
+    /// client.change_password("old password", "new password").await?;
+    /// // Don't forget to save the new credentials
+    /// let auth = client.auth().await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn change_password(&self, old_password: &str, new_password: &str) -> Result<&Self> {
+        if !self.inner.token_provider.is_auth().await {
+            return Err(Error::Unauthenticated);
+        }
+
+        ChangePasswordQuery::new(old_password, new_password)
+            .send_request(self)
+            .await?;
+
+        self.inner.token_provider.rotate(self).await?;
+        Ok(self)
+    }
+
+    /// Retrieves a list of active sessions of the current user, including the one the client is
+    /// authenticated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if an error occurs while sending the request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #     let client = &Client::default();
+    /// for session in client.list_sessions().await? {
+    ///     println!("{} ({})", session.user_agent, session.ip);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn list_sessions(&self) -> Result<Vec<Session>> {
+        Ok(AccountSecurityQuery::new().send_request(self).await?.into())
+    }
+
+    /// Retrieves the sign-in methods linked to the current user's account.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if an error occurs while sending the request.
+    pub async fn get_linked_logins(&self) -> Result<LinkedLogins> {
+        Ok(AccountSecurityQuery::new().send_request(self).await?.into())
+    }
+
+    /// Retrieves a [`Stream`] of OAuth grants given by the current user to third-party
+    /// applications.
+    ///
+    /// This method returns a [`Stream`] that yields individual [`OAuthGrant`] instances as they
+    /// are retrieved. The stream handles pagination automatically, fetching new pages of results
+    /// as needed. The `offset` parameter can be used to skip a number of grants from the beginning
+    /// of the list. If an [`Error`] occurs during the retrieval of any page, the stream will yield
+    /// that single error and then terminate.
+    pub fn list_oauth_grants(&self, offset: usize) -> impl Stream<Item = Result<OAuthGrant>> + '_ {
+        auto_paginated_stream(
+            move |offset| async move {
+                Ok(ListOAuthGrantsQuery::new(offset)
+                    .send_request(self)
+                    .await?
+                    .into())
+            },
+            offset,
+            ListOAuthGrantsQuery::PAGE_SIZE,
+        )
+    }
+
     /// Retrieves the current authentication credentials.
     ///
     /// This method ensures the returned credentials are valid by automatically refreshing them if
-    /// they are expired.
+    /// they are expired or about to expire (see [`Builder::refresh_margin()`]).
     ///
-    /// This method is typically called at the end of a program's execution to save the valid
-    /// credentials securely for use in [`Builder::auth()`] when the program restarts.
+    /// This method can be called at the end of a program's execution to save the valid credentials
+    /// securely for use in [`Builder::auth()`] when the program restarts. Prefer
+    /// [`Builder::auth_store()`] instead, which also saves the credentials rotated by refreshes
+    /// that happen in between.
     ///
     /// # Errors
     ///
@@ -288,6 +512,9 @@ impl Client {
     /// state. It does not perform any immediate validation or token refreshing; these operations
     /// occur lazily when subsequent authenticated requests are sent.
     ///
+    /// If an [`AuthStore`] is set, the credentials are saved to it, or cleared from it if `auth` is
+    /// `None`.
+    ///
     /// # Errors
     ///
     /// Returns [`JwtError`] if an error occurs while parsing the provided credentials.
@@ -334,14 +561,14 @@ impl Client {
         let android = self
             .inner
             .fcm_service
-            .register_android(&self.inner.hyper_client)
+            .register_android(&self.transport())
             .await
             .inspect_err(|error| tracing::error!(?error, "failed to register with GCM"))?;
 
         tracing::info!("registering with FCM");
         self.inner
             .fcm_service
-            .register(&self.inner.hyper_client, android)
+            .register(&self.transport(), android)
             .await
             .inspect_err(|error| tracing::error!(?error, "failed to register with FCM"))
     }
@@ -365,11 +592,22 @@ impl Client {
         tracing::info!("registering with FCM");
         self.inner
             .fcm_service
-            .register(&self.inner.hyper_client, android)
+            .register(&self.transport(), android)
             .await
             .inspect_err(|error| tracing::error!(?error, "failed to register with FCM"))
     }
 
+    /// Creates a [`PushListener`] for the given Android registration data.
+    ///
+    /// Unlike [`PushListener::new()`], the listener connects to the MCS server through the
+    /// [proxy][Builder::proxy()] set for the client, if there is one.
+    #[cfg(feature = "fcm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fcm")))]
+    #[must_use]
+    pub fn push_listener(&self, android: FcmAndroidRegistration) -> PushListener {
+        self.inner.fcm_service.listener(android)
+    }
+
     /// Unregisters from FCM to stop receiving push notifications.
     ///
     /// This method removes the provided token from FCM servers, stopping all push notifications for
@@ -386,30 +624,159 @@ impl Client {
         tracing::info!("unregistering from FCM");
         self.inner
             .fcm_service
-            .unregister(
-                &self.inner.hyper_client,
-                &credentials.android,
-                &credentials.token,
-            )
+            .unregister(&self.transport(), &credentials.android, &credentials.token)
             .await
             .inspect_err(|error| tracing::error!(?error, "failed to unregister from FCM"))
     }
 
+    /// Returns a handle to the same client that uses a different request timeout.
+    ///
+    /// The handle shares the session, the rate limiter and everything else with the client, so it
+    /// can be passed to any method to override the timeout set with
+    /// [`Builder::request_timeout()`] for a single call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// use std::time::Duration;
+    ///
+    /// use bonfire::models::Account;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let client = &Client::default();
+    /// let account = Account::get_by_id(&client.with_timeout(Duration::from_secs(5)), 1).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            request_timeout: Some(timeout),
+        }
+    }
+
+    /// Sends a Root request that isn't modeled by the library and returns the raw response.
+    ///
+    /// `params` contains the request's fields, except for the name, the access token and the API
+    /// version, which are added by the client. The request goes through the rate limiter and is
+    /// authenticated like any other request, but it's never retried, since it may not be safe to
+    /// send it twice.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::ConversionError`] if `params` is neither a JSON object nor `null`.
+    /// * Returns [`Error::AttachmentTooLarge`] if an attachment exceeds the maximum size.
+    /// * Returns [`Error::RootError`] if the server returns an error.
+    /// * Returns [`Error`] if any other error occurs while sending the request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// use serde_json::json;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let client = &Client::default();
+    /// let response = client
+    ///     .root_call("RAccountsGet", json!({"accountId": 1}), Vec::new())
+    ///     .await?;
+    /// println!("{}", response["account"]["J_NAME"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn root_call(
+        &self,
+        name: &str,
+        params: serde_json::Value,
+        attachments: Vec<&[u8]>,
+    ) -> Result<serde_json::Value> {
+        let params = match params {
+            serde_json::Value::Object(params) => params,
+            serde_json::Value::Null => serde_json::Map::new(),
+            _ => {
+                return Err(Error::ConversionError(
+                    "root request parameters must be a JSON object".to_owned(),
+                ));
+            }
+        };
+
+        RootCallRequest::new(name, params, attachments)
+            .send_request(self)
+            .await
+    }
+
+    /// Sends a GraphQL operation to the Melior server and returns the raw `data` of the response.
+    ///
+    /// This allows sending operations that aren't modeled by the library. The operation goes
+    /// through the rate limiter and is authenticated like any other request, but it's never
+    /// retried, since it may be a mutation.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::MeliorError`] if the server returns an error.
+    /// * Returns [`Error`] if any other error occurs while sending the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// use serde_json::json;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let client = &Client::default();
+    /// let data = client
+    ///     .graphql(
+    ///         "MeQuery",
+    ///         "query MeQuery { me { id username } }",
+    ///         json!({}),
+    ///     )
+    ///     .await?;
+    /// println!("{}", data["me"]["username"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn graphql(
+        &self,
+        operation_name: &str,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        GraphqlQuery::new(operation_name, query, variables)
+            .send_request(self)
+            .await
+    }
+
+    // The ID of the authenticated user's account is the subject of the access token
+    pub(crate) async fn account_id(&self) -> Result<u64> {
+        let auth = self
+            .inner
+            .token_provider
+            .auth(self)
+            .await?
+            .ok_or(Error::Unauthenticated)?;
+        let subject = jwt::decode_token(&auth.access_token)?.subject;
+        subject.parse().map_err(|error| {
+            Error::ConversionError(format!(
+                "failed to convert token subject into u64 ({error})"
+            ))
+        })
+    }
+
     #[instrument(skip(self, content, attachments))]
     pub(crate) async fn send_request<R: Request>(
         &self,
-        request_name: &'static str,
+        request_name: &str,
         content: &R,
         attachments: Vec<&[u8]>,
     ) -> Result<R::Response>
     where
         for<'a> &'a <R::Error as RequestError>::Source: From<&'a RootError>,
     {
-        self.inner.rate_limiter.until_ready().await;
-
-        tracing::debug!("obtaining auth token");
-        let token = self.inner.token_provider.token(self).await?;
-
         // Contains the length of each attachment
         let data_output = attachments
             .iter()
@@ -427,30 +794,50 @@ impl Client {
                     })
                     .transpose()
             })
-            .collect::<Result<_>>()?;
-
-        let request = RootRequest {
-            content,
-            request_name,
-            data_output,
-            api_access_token: token.as_deref(),
-            api_version: API_VERSION,
-        };
+            .collect::<Result<Vec<_>>>()?;
 
-        tracing::info!("sending request");
-        self.inner
-            .root_service
-            .send_request(
-                &self.inner.hyper_client,
-                request,
-                attachments,
-                HeaderMap::new(),
-            )
-            .await
-            .inspect_err(|error| tracing::error!(?error, "failed to send request"))
+        tracing::debug!("obtaining auth token");
+        let mut token = self.inner.token_provider.token(self).await?;
+        let mut is_retry = false;
+        loop {
+            let request = RootRequest {
+                content,
+                request_name,
+                data_output: data_output.clone(),
+                api_access_token: token.as_deref(),
+                api_version: API_VERSION,
+            };
+
+            tracing::info!("sending request");
+            let result = self
+                .inner
+                .root_service
+                .send_request(
+                    &self.dispatcher::<R>(),
+                    request,
+                    attachments.clone(),
+                    HeaderMap::new(),
+                )
+                .await;
+
+            match (result, &token) {
+                (Err(error), Some(rejected_token)) if !is_retry && error.is_token_rejected() => {
+                    tracing::warn!(?error, "auth token was rejected, refreshing");
+                    token = self
+                        .inner
+                        .token_provider
+                        .force_refresh(self, rejected_token)
+                        .await?;
+                    is_retry = true;
+                }
+                (result, _) => {
+                    return result
+                        .inspect_err(|error| tracing::error!(?error, "failed to send request"));
+                }
+            }
+        }
     }
 
-    #[instrument(skip(self, graphql_path, variables))]
     pub(crate) async fn send_query<R: Request>(
         &self,
         operation_name: &'static str,
@@ -460,31 +847,61 @@ impl Client {
     where
         for<'a> &'a <R::Error as RequestError>::Source: From<&'a MeliorError>,
     {
-        self.inner.rate_limiter.until_ready().await;
+        self.send_graphql(operation_name, graphql::contents(graphql_path), variables)
+            .await
+    }
 
+    #[instrument(skip(self, query, variables))]
+    pub(crate) async fn send_graphql<R: Request>(
+        &self,
+        operation_name: &str,
+        query: &str,
+        variables: &R,
+    ) -> Result<R::Response>
+    where
+        for<'a> &'a <R::Error as RequestError>::Source: From<&'a MeliorError>,
+    {
         tracing::debug!("obtaining auth token");
-        let token = self.inner.token_provider.token(self).await?;
-
-        let query = MeliorQuery {
-            operation_name,
-            variables,
-            query: graphql::contents(graphql_path),
-        };
-
-        let mut headers = HeaderMap::new();
-        if let Some(token) = token {
-            headers.insert(
-                header::AUTHORIZATION,
-                format!("Bearer {token}").parse().unwrap(),
-            );
+        let mut token = self.inner.token_provider.token(self).await?;
+        let mut is_retry = false;
+        loop {
+            let query = MeliorQuery {
+                operation_name,
+                variables,
+                query,
+            };
+
+            let mut headers = HeaderMap::new();
+            if let Some(token) = &token {
+                headers.insert(
+                    header::AUTHORIZATION,
+                    format!("Bearer {token}").parse().unwrap(),
+                );
+            }
+
+            tracing::info!("sending query");
+            let result = self
+                .inner
+                .melior_service
+                .send_query(&self.dispatcher::<R>(), query, headers)
+                .await;
+
+            match (result, &token) {
+                (Err(error), Some(rejected_token)) if !is_retry && error.is_token_rejected() => {
+                    tracing::warn!(?error, "auth token was rejected, refreshing");
+                    token = self
+                        .inner
+                        .token_provider
+                        .force_refresh(self, rejected_token)
+                        .await?;
+                    is_retry = true;
+                }
+                (result, _) => {
+                    return result
+                        .inspect_err(|error| tracing::error!(?error, "failed to send query"));
+                }
+            }
         }
-
-        tracing::info!("sending query");
-        self.inner
-            .melior_service
-            .send_query(&self.inner.hyper_client, query, headers)
-            .await
-            .inspect_err(|error| tracing::error!(?error, "failed to send query"))
     }
 
     #[instrument(skip(self, graphql_path, variables))]
@@ -497,8 +914,6 @@ impl Client {
     where
         for<'a> &'a <R::Error as RequestError>::Source: From<&'a MeliorError>,
     {
-        self.inner.rate_limiter.until_ready().await;
-
         let query = MeliorQuery {
             operation_name,
             variables,
@@ -508,11 +923,26 @@ impl Client {
         tracing::info!("sending query without auth");
         self.inner
             .melior_service
-            .send_query(&self.inner.hyper_client, query, HeaderMap::new())
+            .send_query(&self.dispatcher::<R>(), query, HeaderMap::new())
             .await
             .inspect_err(|error| tracing::error!(?error, "failed to send an authless query"))
     }
 
+    fn transport(&self) -> TimedTransport<'_> {
+        TimedTransport {
+            transport: &*self.inner.transport,
+            timeout: self.request_timeout.or(self.inner.request_timeout),
+        }
+    }
+
+    fn dispatcher<R: Request>(&self) -> Dispatcher<'_> {
+        Dispatcher {
+            transport: self.transport(),
+            rate_limiter: &self.inner.rate_limiter,
+            retry_policy: R::IS_RETRYABLE.then_some(&self.inner.retry_policy),
+        }
+    }
+
     /// Create a new `Builder` with default values.
     #[must_use]
     pub fn builder() -> Builder {
@@ -0,0 +1,186 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use governor::clock::{Clock as _, DefaultClock};
+use governor::{DefaultDirectRateLimiter, Quota};
+use http::StatusCode;
+use nonzero_ext::nonzero;
+use tokio::time;
+
+use crate::{Error, Result};
+
+// 30 requests per minute with a burst of 15 requests
+pub(super) const DEFAULT_QUOTA: Quota =
+    Quota::per_minute(nonzero!(30u32)).allow_burst(nonzero!(15u32));
+
+// How long a budget is paused after a `429 Too Many Requests` response without a `Retry-After`
+// header. The pause doubles with every consecutive 429
+const INITIAL_PAUSE: Duration = Duration::from_secs(1);
+const MAX_PAUSE: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Server {
+    Root,
+    Melior,
+}
+
+/// A rate limiter that keeps the requests sent to the Bonfire servers within the server's limits.
+///
+/// Requests to the Root and Melior servers are limited separately, each with its own quota. The
+/// limiter also adapts to the server: after a `429 Too Many Requests` response, requests to that
+/// server are paused for the time specified by the `Retry-After` header, or for an increasing
+/// period if it's absent.
+///
+/// [`RateLimiter::default()`] creates a limiter with a quota of 30 requests per minute and a burst
+/// of 15 requests for each server.
+///
+/// A `RateLimiter` is a handle that can be cloned and passed to several clients with
+/// [`Builder::rate_limiter()`][crate::client::Builder::rate_limiter()], so that clients using
+/// different accounts from the same IP address share the same budget.
+///
+/// # Examples
+///
+/// ```
+/// # use bonfire::ClientBuilder;
+/// use bonfire::client::RateLimiter;
+/// use governor::Quota;
+/// use nonzero_ext::nonzero;
+///
+/// let limiter = RateLimiter::new(
+///     Quota::per_minute(nonzero!(30u32)),
+///     Quota::per_minute(nonzero!(60u32)),
+/// );
+/// let first_client = &ClientBuilder::new()
+///     .rate_limiter(limiter.clone())
+///     .build();
+/// let second_client = &ClientBuilder::new().rate_limiter(limiter).build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    root: Arc<Budget>,
+    melior: Arc<Budget>,
+    is_blocking: bool,
+}
+impl RateLimiter {
+    /// Creates a new `RateLimiter` with separate quotas for the Root and Melior servers.
+    #[must_use]
+    pub fn new(root_quota: Quota, melior_quota: Quota) -> Self {
+        Self {
+            root: Arc::new(Budget::new(root_quota)),
+            melior: Arc::new(Budget::new(melior_quota)),
+            is_blocking: true,
+        }
+    }
+
+    /// Enables or disables the blocking mode. Enabled by default.
+    ///
+    /// In the blocking mode, requests wait until they fit into the quota. Otherwise, they fail
+    /// immediately with [`Error::RateLimited`], which contains the time to wait before sending the
+    /// request again.
+    ///
+    /// The mode only applies to the returned handle and its clones, so clients sharing the same
+    /// budget can use different modes.
+    #[must_use]
+    pub fn blocking(mut self, enabled: bool) -> Self {
+        self.is_blocking = enabled;
+        self
+    }
+
+    // Waits until a request can be sent to `server`, or returns `Error::RateLimited` in the
+    // non-blocking mode
+    pub(super) async fn acquire(&self, server: Server) -> Result<()> {
+        let budget = self.budget(server);
+        if self.is_blocking {
+            while let Some(pause) = budget.remaining_pause() {
+                tracing::debug!(?pause, "rate limit is paused, waiting");
+                time::sleep(pause).await;
+            }
+            budget.limiter.until_ready().await;
+            return Ok(());
+        }
+
+        if let Some(retry_after) = budget.remaining_pause() {
+            return Err(Error::RateLimited { retry_after });
+        }
+        budget
+            .limiter
+            .check()
+            .map_err(|not_until| Error::RateLimited {
+                retry_after: not_until.wait_time_from(DefaultClock::default().now()),
+            })
+    }
+
+    // Adapts to the server's response
+    pub(super) fn record(&self, server: Server, status: StatusCode, retry_after: Option<Duration>) {
+        let budget = self.budget(server);
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            budget.pause(retry_after);
+        } else if status.is_success() {
+            budget.reset_backoff();
+        }
+    }
+
+    fn budget(&self, server: Server) -> &Budget {
+        match server {
+            Server::Root => &self.root,
+            Server::Melior => &self.melior,
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUOTA, DEFAULT_QUOTA)
+    }
+}
+
+#[derive(Debug)]
+struct Budget {
+    limiter: DefaultDirectRateLimiter,
+    pause: Mutex<PauseState>,
+}
+
+#[derive(Debug)]
+struct PauseState {
+    until: Option<Instant>,
+    // The pause to use after the next 429 without a `Retry-After` header
+    next: Duration,
+}
+
+impl Budget {
+    fn new(quota: Quota) -> Self {
+        Self {
+            limiter: DefaultDirectRateLimiter::direct(quota),
+            pause: Mutex::new(PauseState {
+                until: None,
+                next: INITIAL_PAUSE,
+            }),
+        }
+    }
+
+    fn remaining_pause(&self) -> Option<Duration> {
+        let state = self.pause.lock().unwrap();
+        state
+            .until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    fn pause(&self, retry_after: Option<Duration>) {
+        let mut state = self.pause.lock().unwrap();
+        let pause = retry_after.unwrap_or(state.next);
+        state.next = (state.next * 2).min(MAX_PAUSE);
+
+        // Concurrent 429s must not shorten an already longer pause
+        let until = Instant::now() + pause;
+        if state.until.is_none_or(|current| current < until) {
+            tracing::warn!(?pause, "server rate limit was hit, pausing requests");
+            state.until = Some(until);
+        }
+    }
+
+    // A response to a request sent before the pause doesn't end it, so only the backoff is reset
+    fn reset_backoff(&self) {
+        self.pause.lock().unwrap().next = INITIAL_PAUSE;
+    }
+}
@@ -10,6 +10,10 @@ pub(crate) trait Request: Serialize {
     type Response: DeserializeOwned;
     type Error: RequestError;
 
+    // Requests that must not be sent twice (e.g., because they create something, rotate a token
+    // or send an email) should set this to `false`, so they're never retried
+    const IS_RETRYABLE: bool = true;
+
     async fn send_request(&self, client: &Client) -> Result<Self::Response>;
 }
 
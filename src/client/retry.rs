@@ -0,0 +1,157 @@
+use std::hash::{BuildHasher as _, Hasher as _, RandomState};
+use std::time::Duration;
+
+use http::StatusCode;
+
+use crate::Error;
+
+/// A policy that decides whether and when a request that failed due to a transient error is sent
+/// again.
+///
+/// Requests are retried when the server answers with `429 Too Many Requests`,
+/// `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`, or when a connection
+/// or [transport][crate::client::Transport] error occurs or a [timeout][Error::Timeout] expires.
+/// The delay before each retry grows exponentially, unless the server specifies it with the
+/// `Retry-After` header, and never exceeds the [maximum backoff][RetryPolicy::max_backoff()].
+/// Every attempt waits for the client's rate limiter, so retries count towards the
+/// [quota][crate::client::Builder::quota()] as well.
+///
+/// Requests that must not be sent twice, like sending a message or reporting an account, are
+/// never retried.
+///
+/// # Examples
+///
+/// ```
+/// # use bonfire::ClientBuilder;
+/// use std::time::Duration;
+///
+/// use bonfire::client::RetryPolicy;
+///
+/// let client = &ClientBuilder::new()
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_secs(1)),
+///     )
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy` that makes at most 3 attempts, starting with a backoff of 500
+    /// milliseconds that doesn't exceed 30 seconds, with jitter enabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// Creates a new `RetryPolicy` that never retries requests.
+    #[must_use]
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Sets the maximum number of attempts, including the first one. A value of `0` is treated as
+    /// `1`.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry. Each subsequent retry doubles it.
+    #[must_use]
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper limit of the backoff. It also applies to delays specified by the server, so a
+    /// long `Retry-After` doesn't stall the request.
+    #[must_use]
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Enables or disables jitter. With jitter enabled, a random delay of up to half of the backoff
+    /// is subtracted from it, so clients that failed at the same time don't retry at the same time.
+    #[must_use]
+    pub fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    // Returns the delay before the next attempt, or `None` if the request shouldn't be retried.
+    // `attempt` is the number of the attempt that has just failed, starting from 1
+    pub(super) fn delay(
+        &self,
+        attempt: u32,
+        error: &Error,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !Self::is_transient(error) {
+            return None;
+        }
+
+        Some(retry_after.map_or_else(
+            || self.backoff(attempt),
+            |retry_after| retry_after.min(self.max_backoff),
+        ))
+    }
+
+    fn is_transient(error: &Error) -> bool {
+        match error {
+            Error::UnsuccessfulResponse(status) => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Error::HyperClientError(_) | Error::Timeout { .. } | Error::TransportError(_) => true,
+            _ => false,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+
+        let half = backoff / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// The default policy never retries requests, just like a client without a policy set with
+/// [`Builder::retry_policy()`][crate::client::Builder::retry_policy()]. Use [`RetryPolicy::new()`]
+/// for a policy that does.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+// Returns a random number in the range [0, 1). The standard library doesn't provide a random
+// number generator, but every `RandomState` is seeded with new random keys, which is enough for
+// jitter
+#[expect(clippy::cast_precision_loss)]
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
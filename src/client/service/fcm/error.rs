@@ -1,6 +1,10 @@
+use std::io;
+
 use thiserror::Error;
+use tokio_rustls::rustls::pki_types::InvalidDnsNameError;
 
-/// Represents errors that can occur during FCM registration or unregistration.
+/// Represents errors that can occur during FCM registration, unregistration, while listening for
+/// push notifications, or while decrypting them.
 #[non_exhaustive]
 #[derive(Error, Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "fcm")))]
@@ -8,15 +12,39 @@ pub enum Error {
     /// The Android registration request was rejected by the server
     #[error("Android registration error: {0}")]
     AndroidRegistrationError(String),
+    /// An error occurred while decoding base64 data
+    #[error("base64 error")]
+    Base64Error(#[from] base64::DecodeError),
     /// An error occurred during ECE (Encrypted Content-Encoding) encryption or decryption
     #[error("ECE error")]
     EceError(#[from] ece::Error),
+    /// The MCS endpoint host is not a valid DNS name
+    #[error("invalid DNS name")]
+    InvalidDnsName(#[from] InvalidDnsNameError),
+    /// An I/O error occurred on the MCS connection
+    #[error("I/O error")]
+    IoError(#[from] io::Error),
     /// The server returned an invalid Android registration response.
     ///
     /// This typically happens when the response contains neither a valid token nor an error
     /// message.
     #[error("invalid Android registration response")]
     InvalidAndroidRegistrationResponse,
+    /// The push message is missing the encrypted payload or the encryption parameters needed to
+    /// decrypt it
+    #[error("invalid push message: {0}")]
+    InvalidPushMessage(String),
+    /// The MCS server rejected the login request
+    #[error("MCS login error ({code}): {message}")]
+    McsLoginError {
+        /// The error code returned by the server
+        code: i32,
+        /// The error message returned by the server
+        message: String,
+    },
+    /// The MCS server closed the connection or sent a message that violates the protocol
+    #[error("MCS protocol error: {0}")]
+    McsProtocolError(String),
     /// An error occurred while decoding Protocol Buffers data
     #[error("Protobuf decode error")]
     ProtobufDecodeError(#[from] prost::DecodeError),
@@ -0,0 +1,238 @@
+use std::io;
+use std::sync::Arc;
+
+use bytes::{Buf as _, Bytes, BytesMut};
+use http::Uri;
+use tokio::io::{AsyncRead, AsyncReadExt as _, AsyncWrite, AsyncWriteExt as _};
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+use crate::client::proxy::ProxyConnector;
+use crate::client::{FcmError, Proxy};
+use crate::{Error, Result};
+
+// The version of the MCS protocol this client speaks. Servers may answer with an older one, but
+// nothing older than 38 is supported
+const MCS_VERSION: u8 = 41;
+const MCS_MIN_VERSION: u8 = 38;
+
+const DEFAULT_PORT: u16 = 5228;
+
+// A varint32 is encoded into at most 5 bytes
+const VARINT_MAX_LENGTH: usize = 5;
+
+// The largest message we're willing to buffer (data messages are limited to 4 KiB by FCM, but
+// leave some room for the rest of the stanza)
+const MESSAGE_MAX_SIZE: usize = 64 * 1024;
+
+trait Io: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Io for T {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Tag {
+    HeartbeatPing,
+    HeartbeatAck,
+    LoginRequest,
+    LoginResponse,
+    Close,
+    IqStanza,
+    DataMessageStanza,
+    Unknown(u8),
+}
+
+impl From<u8> for Tag {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Tag::HeartbeatPing,
+            1 => Tag::HeartbeatAck,
+            2 => Tag::LoginRequest,
+            3 => Tag::LoginResponse,
+            4 => Tag::Close,
+            7 => Tag::IqStanza,
+            8 => Tag::DataMessageStanza,
+            other => Tag::Unknown(other),
+        }
+    }
+}
+
+impl From<Tag> for u8 {
+    fn from(value: Tag) -> Self {
+        match value {
+            Tag::HeartbeatPing => 0,
+            Tag::HeartbeatAck => 1,
+            Tag::LoginRequest => 2,
+            Tag::LoginResponse => 3,
+            Tag::Close => 4,
+            Tag::IqStanza => 7,
+            Tag::DataMessageStanza => 8,
+            Tag::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+// Each side numbers the messages it sends, starting at 1 with the login request and response. The
+// stream IDs themselves aren't transmitted, only the last one received from the other side
+pub(super) struct Connection {
+    stream: Box<dyn Io>,
+    buffer: BytesMut,
+    is_version_received: bool,
+    last_stream_id_sent: i32,
+    last_stream_id_received: i32,
+}
+impl Connection {
+    // `https` endpoints are connected to over TLS, `http` ones are connected to over plain TCP
+    // (which is only useful for testing against a local server)
+    pub(super) async fn connect(endpoint: &Uri, proxy: Option<&Proxy>) -> Result<Self> {
+        let host = endpoint.host().ok_or(FcmError::McsProtocolError(
+            "endpoint has no host".to_owned(),
+        ))?;
+        let port = endpoint.port_u16().unwrap_or(DEFAULT_PORT);
+
+        let destination = Uri::builder()
+            .scheme(endpoint.scheme_str().unwrap_or("https"))
+            .authority(format!("{host}:{port}"))
+            .path_and_query("/")
+            .build()?;
+        let tcp_stream = ProxyConnector::new(proxy.cloned(), None)
+            .connect(destination)
+            .await
+            .map_err(|error| FcmError::from(io::Error::other(error)))?;
+        tcp_stream.set_nodelay(true).map_err(FcmError::from)?;
+
+        let mut stream: Box<dyn Io> = if endpoint.scheme_str() == Some("http") {
+            Box::new(tcp_stream)
+        } else {
+            let mut root_store = RootCertStore::empty();
+            root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+            let config = ClientConfig::builder()
+                .with_root_certificates(root_store)
+                .with_no_client_auth();
+            let server_name = ServerName::try_from(host.to_owned()).map_err(FcmError::from)?;
+
+            Box::new(
+                TlsConnector::from(Arc::new(config))
+                    .connect(server_name, tcp_stream)
+                    .await
+                    .map_err(FcmError::from)?,
+            )
+        };
+
+        // The version is sent once, right before the first message
+        stream
+            .write_all(&[MCS_VERSION])
+            .await
+            .map_err(FcmError::from)?;
+
+        Ok(Self {
+            stream,
+            buffer: BytesMut::new(),
+            is_version_received: false,
+            last_stream_id_sent: 0,
+            last_stream_id_received: 0,
+        })
+    }
+
+    pub(super) fn last_stream_id_sent(&self) -> i32 {
+        self.last_stream_id_sent
+    }
+
+    pub(super) fn last_stream_id_received(&self) -> i32 {
+        self.last_stream_id_received
+    }
+
+    pub(super) async fn send(&mut self, tag: Tag, message: &impl prost::Message) -> Result<()> {
+        let mut frame = Vec::with_capacity(1 + VARINT_MAX_LENGTH + message.encoded_len());
+        frame.push(tag.into());
+        message
+            .encode_length_delimited(&mut frame)
+            .map_err(FcmError::from)?;
+
+        self.stream
+            .write_all(&frame)
+            .await
+            .map_err(FcmError::from)?;
+        self.stream.flush().await.map_err(FcmError::from)?;
+        self.last_stream_id_sent += 1;
+        Ok(())
+    }
+
+    // This method is cancel-safe: a partially received message stays in the buffer until the next
+    // call
+    pub(super) async fn receive(&mut self) -> Result<(Tag, Bytes)> {
+        loop {
+            if let Some(frame) = self.parse_frame()? {
+                self.last_stream_id_received += 1;
+                return Ok(frame);
+            }
+
+            let read = self
+                .stream
+                .read_buf(&mut self.buffer)
+                .await
+                .map_err(FcmError::from)?;
+            if read == 0 {
+                return Err(
+                    FcmError::McsProtocolError("connection closed by server".to_owned()).into(),
+                );
+            }
+        }
+    }
+
+    fn parse_frame(&mut self) -> Result<Option<(Tag, Bytes)>> {
+        if !self.is_version_received {
+            let Some(&version) = self.buffer.first() else {
+                return Ok(None);
+            };
+            if version < MCS_MIN_VERSION {
+                return Err(protocol_error(format!("unsupported MCS version {version}")));
+            }
+
+            self.buffer.advance(1);
+            self.is_version_received = true;
+        }
+
+        // A frame consists of a tag, a varint32 length and the message itself
+        let Some(&tag) = self.buffer.first() else {
+            return Ok(None);
+        };
+
+        let mut length: usize = 0;
+        let mut length_size = 0;
+        loop {
+            let Some(&byte) = self.buffer.get(1 + length_size) else {
+                return Ok(None);
+            };
+
+            length |= usize::from(byte & 0x7f) << (7 * length_size);
+            length_size += 1;
+
+            if byte & 0x80 == 0 {
+                break;
+            } else if length_size == VARINT_MAX_LENGTH {
+                return Err(protocol_error("malformed message length".to_owned()));
+            }
+        }
+
+        if length > MESSAGE_MAX_SIZE {
+            return Err(protocol_error(format!(
+                "message is too large ({length} bytes)"
+            )));
+        }
+
+        let header_size = 1 + length_size;
+        if self.buffer.len() < header_size + length {
+            self.buffer
+                .reserve(header_size + length - self.buffer.len());
+            return Ok(None);
+        }
+
+        self.buffer.advance(header_size);
+        Ok(Some((tag.into(), self.buffer.split_to(length).freeze())))
+    }
+}
+
+fn protocol_error(message: String) -> Error {
+    FcmError::McsProtocolError(message).into()
+}
@@ -0,0 +1,389 @@
+mod connection;
+#[expect(warnings)]
+mod mcs_proto {
+    include!(concat!(env!("OUT_DIR"), "/mcs_proto.rs"));
+}
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use chrono::DateTime;
+use connection::{Connection, Tag};
+use futures::{Stream, stream};
+use http::Uri;
+use prost::Message as _;
+use tokio::sync::mpsc;
+use tokio::time::{self, Instant, MissedTickBehavior};
+
+use crate::client::{FcmError, Proxy};
+use crate::models::{FcmAndroidRegistration, FcmMessage};
+use crate::{Error, Result};
+
+static MCS_SERVER_URI: LazyLock<Uri> =
+    LazyLock::new(|| Uri::from_static("https://mtalk.google.com:5228"));
+
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+const RECONNECT_MIN_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(5 * 60);
+
+// The ID of an IqStanza extension containing a SelectiveAck
+const SELECTIVE_ACK_EXTENSION_ID: i32 = 12;
+
+// How many messages can be received without being consumed from the stream, both in the channel
+// and waiting to be sent into it
+const CHANNEL_CAPACITY: usize = 64;
+
+/// A listener that receives push notifications from FCM over the MCS (Mobile Connection Server)
+/// protocol.
+///
+/// The listener authenticates with the Android registration data obtained from
+/// [`Client::register_fcm()`][crate::Client::register_fcm], keeps the connection alive with
+/// heartbeats, acknowledges every received message once it's passed to the stream, and reconnects
+/// with an exponential backoff whenever the connection is lost.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bonfire::{Client, Result};
+/// use futures::StreamExt as _;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// #     let client = &Client::default();
+/// let credentials = client.register_fcm().await?;
+/// let mut messages = Box::pin(client.push_listener(credentials.android).listen());
+///
+/// while let Some(message) = messages.next().await {
+///     println!("{:#?}", message?);
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "fcm")))]
+pub struct Listener {
+    android: FcmAndroidRegistration,
+    endpoint: Uri,
+    heartbeat_interval: Duration,
+    // Persistent IDs to report in the next login request
+    received_ids: Vec<String>,
+    // Persistent IDs acknowledged in the current session, along with the stream ID of their
+    // acknowledgement. They are also reported in the next login request until the server confirms
+    // receiving the acknowledgement
+    unconfirmed_ids: Vec<(i32, String)>,
+    proxy: Option<Proxy>,
+}
+impl Listener {
+    /// Creates a new `PushListener` using the given Android registration data and the default
+    /// MCS endpoint.
+    #[must_use]
+    pub fn new(android: FcmAndroidRegistration) -> Self {
+        Self {
+            android,
+            endpoint: MCS_SERVER_URI.clone(),
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            received_ids: Vec::new(),
+            unconfirmed_ids: Vec::new(),
+            proxy: None,
+        }
+    }
+
+    /// Sets the URI of the MCS server.
+    ///
+    /// An `https` URI is connected to over TLS, while an `http` one is connected to over plain TCP,
+    /// which is useful for testing against a local server. The port defaults to `5228` if not
+    /// specified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided argument cannot be converted to a valid `Uri`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bonfire::client::PushListener;
+    /// # use bonfire::models::FcmAndroidRegistration;
+    /// # fn f(android: FcmAndroidRegistration) {
+    /// let listener = PushListener::new(android).endpoint("http://localhost:5228");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn endpoint<T>(mut self, uri: T) -> Self
+    where
+        Uri: TryFrom<T>,
+        <Uri as TryFrom<T>>::Error: fmt::Debug,
+    {
+        self.endpoint = Uri::try_from(uri).unwrap();
+        self
+    }
+
+    /// Sets the proxy to connect to the MCS server through. See [`Proxy`] for details.
+    ///
+    /// A listener created with [`Client::push_listener()`][crate::Client::push_listener] already
+    /// uses the client's proxy.
+    #[must_use]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the interval between heartbeats sent to the MCS server. Defaults to 5 minutes.
+    ///
+    /// If the server doesn't respond to a heartbeat before the next one is due, the connection is
+    /// considered lost and is re-established.
+    #[must_use]
+    pub fn heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    /// Sets the persistent IDs of messages that were already received (e.g., in a previous run),
+    /// so the server doesn't deliver them again.
+    #[must_use]
+    pub fn received_persistent_ids(mut self, ids: impl IntoIterator<Item = String>) -> Self {
+        self.received_ids = ids.into_iter().collect();
+        self
+    }
+
+    /// Consumes the `PushListener` and starts listening for push notifications, returning a
+    /// [`Stream`] of received data messages.
+    ///
+    /// This method spawns a background task that maintains the connection; the task stops when
+    /// the returned stream is dropped. Connection failures are retried indefinitely with an
+    /// exponential backoff. If the server rejects the login, the stream will yield that single
+    /// [`FcmError::McsLoginError`] and then terminate.
+    pub fn listen(self) -> impl Stream<Item = Result<FcmMessage>> {
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        tokio::spawn(self.run(sender));
+
+        stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|item| (item, receiver))
+        })
+    }
+
+    async fn run(mut self, sender: mpsc::Sender<Result<FcmMessage>>) {
+        let mut delay = RECONNECT_MIN_DELAY;
+
+        loop {
+            match self.run_session(&sender, &mut delay).await {
+                Ok(()) => return,
+                Err(error @ Error::FcmError(FcmError::McsLoginError { .. })) => {
+                    tracing::error!(?error, "MCS login rejected");
+                    let _ = sender.send(Err(error)).await;
+                    return;
+                }
+                Err(error) => tracing::warn!(?error, ?delay, "MCS connection lost, reconnecting"),
+            }
+
+            tokio::select! {
+                () = time::sleep(delay) => {}
+                () = sender.closed() => return,
+            }
+            delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+        }
+    }
+
+    // Returns Ok(()) only when the stream has been dropped
+    async fn run_session(
+        &mut self,
+        sender: &mpsc::Sender<Result<FcmMessage>>,
+        delay: &mut Duration,
+    ) -> Result<()> {
+        tracing::debug!(endpoint = %self.endpoint, "connecting to MCS");
+        let mut connection = Connection::connect(&self.endpoint, self.proxy.as_ref()).await?;
+        self.login(&mut connection).await?;
+        tracing::info!("connected to MCS");
+        *delay = RECONNECT_MIN_DELAY;
+
+        let mut heartbeat = time::interval_at(
+            Instant::now() + self.heartbeat_interval,
+            self.heartbeat_interval,
+        );
+        heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut is_awaiting_ack = false;
+        // Data messages waiting for room in the channel. Reading stops while it's full, so a
+        // stalled stream eventually times out the heartbeat instead of buffering without limit
+        let mut pending = VecDeque::new();
+
+        loop {
+            tokio::select! {
+                result = connection.receive(), if pending.len() < CHANNEL_CAPACITY => {
+                    let (tag, bytes) = result?;
+                    // Any message from the server proves the connection is alive
+                    is_awaiting_ack = false;
+
+                    match tag {
+                        Tag::HeartbeatPing => {
+                            let ping = mcs_proto::HeartbeatPing::decode(bytes)
+                                .map_err(FcmError::from)?;
+                            self.confirm_acks(ping.last_stream_id_received);
+
+                            let ack = mcs_proto::HeartbeatAck {
+                                last_stream_id_received: Some(connection.last_stream_id_received()),
+                                ..Default::default()
+                            };
+                            connection.send(Tag::HeartbeatAck, &ack).await?;
+                        }
+                        Tag::HeartbeatAck => {
+                            let ack = mcs_proto::HeartbeatAck::decode(bytes)
+                                .map_err(FcmError::from)?;
+                            self.confirm_acks(ack.last_stream_id_received);
+                        }
+                        Tag::IqStanza => {
+                            let stanza = mcs_proto::IqStanza::decode(bytes)
+                                .map_err(FcmError::from)?;
+                            self.confirm_acks(stanza.last_stream_id_received);
+                        }
+                        Tag::Close => {
+                            return Err(FcmError::McsProtocolError(
+                                "connection closed by server".to_owned(),
+                            )
+                            .into());
+                        }
+                        Tag::DataMessageStanza => {
+                            let stanza = mcs_proto::DataMessageStanza::decode(bytes)
+                                .map_err(FcmError::from)?;
+                            self.confirm_acks(stanza.last_stream_id_received);
+                            pending.push_back(stanza);
+                        }
+                        _ => tracing::trace!(?tag, "ignoring MCS message"),
+                    }
+                }
+                permit = sender.reserve(), if !pending.is_empty() => {
+                    let Ok(permit) = permit else {
+                        return Ok(());
+                    };
+                    let stanza = pending.pop_front().unwrap();
+                    let id = stanza.persistent_id.clone();
+                    permit.send(Ok(stanza.into()));
+
+                    // Only acknowledged once delivered, so the server sends the message again if
+                    // the connection is lost before then
+                    if let Some(id) = id {
+                        self.acknowledge(&mut connection, &id).await?;
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    if is_awaiting_ack {
+                        return Err(FcmError::McsProtocolError(
+                            "heartbeat timed out".to_owned(),
+                        )
+                        .into());
+                    }
+
+                    tracing::trace!("sending heartbeat");
+                    let ping = mcs_proto::HeartbeatPing {
+                        last_stream_id_received: Some(connection.last_stream_id_received()),
+                        ..Default::default()
+                    };
+                    connection.send(Tag::HeartbeatPing, &ping).await?;
+                    is_awaiting_ack = true;
+                }
+                () = sender.closed() => return Ok(()),
+            }
+        }
+    }
+
+    async fn login(&mut self, connection: &mut Connection) -> Result<()> {
+        let android_id = self.android.android_id.to_string();
+        let request = mcs_proto::LoginRequest {
+            adaptive_heartbeat: Some(false),
+            auth_service: Some(mcs_proto::login_request::AuthService::AndroidId.into()),
+            auth_token: self.android.security_token.to_string(),
+            id: "chrome-63.0.3234.0".to_owned(),
+            domain: "mcs.android.com".to_owned(),
+            device_id: Some(format!("android-{:x}", self.android.android_id)),
+            network_type: Some(1),
+            resource: android_id.clone(),
+            user: android_id,
+            use_rmq2: Some(true),
+            setting: vec![mcs_proto::Setting {
+                name: "new_vc".to_owned(),
+                value: "1".to_owned(),
+            }],
+            received_persistent_id: self
+                .received_ids
+                .iter()
+                .chain(self.unconfirmed_ids.iter().map(|(_, id)| id))
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+        connection.send(Tag::LoginRequest, &request).await?;
+
+        let (tag, bytes) = connection.receive().await?;
+        if tag != Tag::LoginResponse {
+            return Err(FcmError::McsProtocolError(format!(
+                "expected a login response, got {tag:?}"
+            ))
+            .into());
+        }
+
+        let response = mcs_proto::LoginResponse::decode(bytes).map_err(FcmError::from)?;
+        if let Some(error) = response.error {
+            return Err(FcmError::McsLoginError {
+                code: error.code,
+                message: error.message.unwrap_or_default(),
+            }
+            .into());
+        }
+
+        // The server won't deliver the messages reported in the login request again
+        self.received_ids.clear();
+        self.unconfirmed_ids.clear();
+        Ok(())
+    }
+
+    async fn acknowledge(&mut self, connection: &mut Connection, id: &str) -> Result<()> {
+        let stanza = mcs_proto::IqStanza {
+            r#type: mcs_proto::iq_stanza::IqType::Set.into(),
+            id: String::new(),
+            last_stream_id_received: Some(connection.last_stream_id_received()),
+            extension: Some(mcs_proto::Extension {
+                id: SELECTIVE_ACK_EXTENSION_ID,
+                data: mcs_proto::SelectiveAck {
+                    id: vec![id.to_owned()],
+                }
+                .encode_to_vec(),
+            }),
+            ..Default::default()
+        };
+        connection.send(Tag::IqStanza, &stanza).await?;
+
+        // Also remember the ID until the server confirms receiving the acknowledgement, in case it
+        // gets lost
+        self.unconfirmed_ids
+            .push((connection.last_stream_id_sent(), id.to_owned()));
+        Ok(())
+    }
+
+    // Forgets the persistent IDs whose acknowledgements have reached the server
+    fn confirm_acks(&mut self, last_stream_id_received: Option<i32>) {
+        if let Some(last_stream_id) = last_stream_id_received {
+            self.unconfirmed_ids
+                .retain(|(stream_id, _)| *stream_id > last_stream_id);
+        }
+    }
+}
+
+impl From<mcs_proto::DataMessageStanza> for FcmMessage {
+    fn from(value: mcs_proto::DataMessageStanza) -> Self {
+        Self {
+            persistent_id: value.persistent_id,
+            from: value.from,
+            category: value.category,
+            app_data: value
+                .app_data
+                .into_iter()
+                .map(|data| (data.key, data.value))
+                .collect(),
+            raw_data: value.raw_data,
+            sent_at: value
+                .sent
+                .and_then(|sent| DateTime::from_timestamp(sent, 0)),
+        }
+    }
+}
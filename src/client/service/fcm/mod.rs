@@ -1,29 +1,41 @@
 mod error;
+mod listener;
 mod requests;
 
 pub use error::Error;
+pub use listener::Listener;
 use requests::{
     AndroidCheckinRequest, AndroidRegisterRequest, InstallationRequest, RegisterRequest,
     UnregisterRequest,
 };
 
 use crate::Result;
-use crate::client::HyperClient;
+use crate::client::{Proxy, Transport};
 use crate::models::{FcmAndroidRegistration, FcmCredentials, FirebaseConfig};
 
 #[derive(Debug)]
 pub(crate) struct FcmService {
     config: FirebaseConfig,
+    // Not needed for the registration requests, which go through the client's transport
+    proxy: Option<Proxy>,
 }
 
 impl FcmService {
-    pub(crate) fn new(config: FirebaseConfig) -> Self {
-        Self { config }
+    pub(crate) fn new(config: FirebaseConfig, proxy: Option<Proxy>) -> Self {
+        Self { config, proxy }
+    }
+
+    pub(crate) fn listener(&self, android: FcmAndroidRegistration) -> Listener {
+        let listener = Listener::new(android);
+        match &self.proxy {
+            Some(proxy) => listener.proxy(proxy.clone()),
+            None => listener,
+        }
     }
 
     pub(crate) async fn register_android(
         &self,
-        client: &HyperClient,
+        transport: &dyn Transport,
     ) -> Result<FcmAndroidRegistration> {
         let (installation_auth_token, (android_id, security_token, gcm_token)) = tokio::try_join!(
             async {
@@ -33,14 +45,14 @@ impl FcmService {
                     &self.config.app_id,
                     &self.config.api_key,
                 )
-                .send_request(client)
+                .send_request(transport)
                 .await
                 .inspect_err(|error| tracing::error!(?error, "failed to send installation request"))
             },
             async {
                 tracing::debug!("sending Android checkin request");
                 let (android_id, security_token) = AndroidCheckinRequest::new()
-                    .send_request(client)
+                    .send_request(transport)
                     .await
                     .inspect_err(|error| {
                         tracing::error!(?error, "failed to send Android checkin request");
@@ -50,7 +62,7 @@ impl FcmService {
                 tracing::debug!("sending Android registration request");
                 let gcm_token =
                     AndroidRegisterRequest::new(&self.config.app_id, android_id, security_token)
-                        .send_request(client)
+                        .send_request(transport)
                         .await
                         .inspect_err(|error| {
                             tracing::error!(?error, "failed to send Android registration request");
@@ -70,7 +82,7 @@ impl FcmService {
 
     pub(crate) async fn register(
         &self,
-        client: &HyperClient,
+        transport: &dyn Transport,
         android: FcmAndroidRegistration,
     ) -> Result<FcmCredentials> {
         let response = RegisterRequest::new(
@@ -80,7 +92,7 @@ impl FcmService {
             &android.installation_auth_token,
             &android.gcm_token,
         )
-        .send_request(client)
+        .send_request(transport)
         .await
         .inspect_err(|error| {
             tracing::error!(?error, "failed to send registration request");
@@ -97,7 +109,7 @@ impl FcmService {
 
     pub(crate) async fn unregister(
         &self,
-        client: &HyperClient,
+        transport: &dyn Transport,
         android: &FcmAndroidRegistration,
         fcm_token: &str,
     ) -> Result<()> {
@@ -107,7 +119,7 @@ impl FcmService {
             &android.installation_auth_token,
             fcm_token,
         )
-        .send_request(client)
+        .send_request(transport)
         .await
         .inspect_err(|error| {
             tracing::error!(?error, "failed to send unregistration request");
@@ -5,10 +5,9 @@ mod checkin_proto {
 
 use bytes::{Bytes, BytesMut};
 use http::{Method, header};
-use http_body_util::{BodyExt as _, Either, Full};
 use prost::Message as _;
 
-use crate::client::{FcmError, HyperClient};
+use crate::client::{FcmError, Transport};
 use crate::{Error, Result};
 
 const URI: &str = "https://android.clients.google.com/checkin";
@@ -39,7 +38,7 @@ impl CheckinRequest {
         Self {}
     }
 
-    pub(crate) async fn send_request(&self, client: &HyperClient) -> Result<Response> {
+    pub(crate) async fn send_request(&self, transport: &dyn Transport) -> Result<Response> {
         let request = checkin_proto::AndroidCheckinRequest {
             checkin: checkin_proto::AndroidCheckinProto {
                 r#type: Some(3),
@@ -58,7 +57,7 @@ impl CheckinRequest {
         let mut payload = BytesMut::with_capacity(request.encoded_len());
         request.encode(&mut payload).map_err(FcmError::from)?;
 
-        let bytes = self.send_raw(client, payload.freeze()).await?;
+        let bytes = self.send_raw(transport, payload.freeze()).await?;
         let response = checkin_proto::AndroidCheckinResponse::decode(bytes).map_err(|error| {
             tracing::error!(?error, "failed to parse Android checkin response");
             FcmError::from(error)
@@ -70,18 +69,18 @@ impl CheckinRequest {
         })
     }
 
-    async fn send_raw(&self, client: &HyperClient, body: Bytes) -> Result<Bytes> {
+    async fn send_raw(&self, transport: &dyn Transport, body: Bytes) -> Result<Bytes> {
         let request = http::Request::builder()
             .uri(URI)
             .method(Method::POST)
             .header(header::CONTENT_TYPE, "application/x-protobuf")
-            .body(Either::Left(Full::new(body)))?;
+            .body(body)?;
 
-        let response = client.request(request).await?;
+        let response = transport.send(request).await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
+            Ok(response.into_body())
         } else {
             Err(Error::UnsuccessfulResponse(status))
         }
@@ -2,12 +2,11 @@ use base64::Engine as _;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use bytes::Bytes;
 use http::{Method, header};
-use http_body_util::{BodyExt as _, Either, Full};
 use rand::random;
 use serde::Deserialize;
 use serde_json::json;
 
-use crate::client::HyperClient;
+use crate::client::Transport;
 use crate::{Error, Result};
 
 const URI: &str = "https://firebaseinstallations.googleapis.com/v1";
@@ -38,7 +37,7 @@ impl<'a> InstallationRequest<'a> {
         }
     }
 
-    pub(crate) async fn send_request(&self, client: &HyperClient) -> Result<String> {
+    pub(crate) async fn send_request(&self, transport: &dyn Transport) -> Result<String> {
         let request = json!({
             "app_id": self.app_id,
             "auth_version": "FIS_v2",
@@ -47,7 +46,7 @@ impl<'a> InstallationRequest<'a> {
         });
 
         let payload = serde_json::to_vec(&request)?;
-        let bytes = self.send_raw(client, Bytes::from(payload)).await?;
+        let bytes = self.send_raw(transport, Bytes::from(payload)).await?;
 
         let response = serde_json::from_slice::<Response>(&bytes).inspect_err(|error| {
             tracing::error!(?error, "failed to parse installation response");
@@ -61,7 +60,7 @@ impl<'a> InstallationRequest<'a> {
         BASE64_URL_SAFE_NO_PAD.encode(bytes)
     }
 
-    async fn send_raw(&self, client: &HyperClient, body: Bytes) -> Result<Bytes> {
+    async fn send_raw(&self, transport: &dyn Transport, body: Bytes) -> Result<Bytes> {
         let uri = format!("{URI}/projects/{}/installations", self.project_id);
 
         let heartbeats = json!({"heartbeats": [], "version": 2});
@@ -73,13 +72,13 @@ impl<'a> InstallationRequest<'a> {
             .header(header::CONTENT_TYPE, "application/json")
             .header("x-firebase-client", encoded_heartbeats)
             .header("x-goog-api-key", self.api_key)
-            .body(Either::Left(Full::new(body)))?;
+            .body(body)?;
 
-        let response = client.request(request).await?;
+        let response = transport.send(request).await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
+            Ok(response.into_body())
         } else {
             Err(Error::UnsuccessfulResponse(status))
         }
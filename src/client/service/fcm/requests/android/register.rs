@@ -2,9 +2,8 @@ use std::collections::HashMap;
 
 use bytes::Bytes;
 use http::{Method, header};
-use http_body_util::{BodyExt as _, Either, Full};
 
-use crate::client::{FcmError, HyperClient};
+use crate::client::{FcmError, Transport};
 use crate::{Error, Result};
 
 const SERVER_KEY: &str =
@@ -26,7 +25,7 @@ impl<'a> RegisterRequest<'a> {
         }
     }
 
-    pub(crate) async fn send_request(&self, client: &HyperClient) -> Result<String> {
+    pub(crate) async fn send_request(&self, transport: &dyn Transport) -> Result<String> {
         let android_id = self.android_id.to_string();
 
         let mut params = HashMap::with_capacity(4);
@@ -36,7 +35,7 @@ impl<'a> RegisterRequest<'a> {
         params.insert("sender", SERVER_KEY);
         let payload = serde_urlencoded::to_string(&params).map_err(FcmError::from)?;
 
-        let bytes = self.send_raw(client, Bytes::from(payload)).await?;
+        let bytes = self.send_raw(transport, Bytes::from(payload)).await?;
         let response =
             serde_urlencoded::from_bytes::<HashMap<&str, String>>(&bytes).map_err(|error| {
                 tracing::error!(?error, "failed to parse Android registration response");
@@ -54,7 +53,7 @@ impl<'a> RegisterRequest<'a> {
         }
     }
 
-    async fn send_raw(&self, client: &HyperClient, body: Bytes) -> Result<Bytes> {
+    async fn send_raw(&self, transport: &dyn Transport, body: Bytes) -> Result<Bytes> {
         let auth = format!("AidLogin {}:{}", self.android_id, self.security_token);
 
         let request = http::Request::builder()
@@ -62,13 +61,13 @@ impl<'a> RegisterRequest<'a> {
             .method(Method::POST)
             .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
             .header(header::AUTHORIZATION, auth)
-            .body(Either::Left(Full::new(body)))?;
+            .body(body)?;
 
-        let response = client.request(request).await?;
+        let response = transport.send(request).await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
+            Ok(response.into_body())
         } else {
             Err(Error::UnsuccessfulResponse(status))
         }
@@ -2,12 +2,11 @@ use base64::Engine;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use bytes::Bytes;
 use http::{Method, header};
-use http_body_util::{BodyExt as _, Either, Full};
 use serde::Deserialize;
 use serde_json::json;
 
 use super::REGISTRATION_URI;
-use crate::client::{FcmError, HyperClient};
+use crate::client::{FcmError, Transport};
 use crate::{Error, Result};
 
 const ENDPOINT_URI: &str = "https://fcm.googleapis.com/fcm";
@@ -47,7 +46,7 @@ impl<'a> RegisterRequest<'a> {
         }
     }
 
-    pub(crate) async fn send_request(&self, client: &HyperClient) -> Result<Response> {
+    pub(crate) async fn send_request(&self, transport: &dyn Transport) -> Result<Response> {
         let endpoint = format!("{ENDPOINT_URI}/send/{}", self.gcm_token);
 
         let (key_pair, auth_secret) =
@@ -67,7 +66,7 @@ impl<'a> RegisterRequest<'a> {
         });
 
         let payload = serde_json::to_vec(&request)?;
-        let bytes = self.send_raw(client, Bytes::from(payload)).await?;
+        let bytes = self.send_raw(transport, Bytes::from(payload)).await?;
 
         let mut response = serde_json::from_slice::<Response>(&bytes).inspect_err(|error| {
             tracing::error!(?error, "failed to parse registration response");
@@ -79,7 +78,7 @@ impl<'a> RegisterRequest<'a> {
         Ok(response)
     }
 
-    async fn send_raw(&self, client: &HyperClient, body: Bytes) -> Result<Bytes> {
+    async fn send_raw(&self, transport: &dyn Transport, body: Bytes) -> Result<Bytes> {
         let uri = format!(
             "{REGISTRATION_URI}/projects/{}/registrations",
             self.project_id
@@ -94,13 +93,13 @@ impl<'a> RegisterRequest<'a> {
                 "x-goog-firebase-installations-auth",
                 self.installation_auth_token,
             )
-            .body(Either::Left(Full::new(body)))?;
+            .body(body)?;
 
-        let response = client.request(request).await?;
+        let response = transport.send(request).await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
+            Ok(response.into_body())
         } else {
             Err(Error::UnsuccessfulResponse(status))
         }
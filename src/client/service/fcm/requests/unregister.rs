@@ -1,9 +1,8 @@
 use bytes::Bytes;
 use http::Method;
-use http_body_util::{BodyExt as _, Either, Empty};
 
 use super::REGISTRATION_URI;
-use crate::client::HyperClient;
+use crate::client::Transport;
 use crate::{Error, Result};
 
 pub(crate) struct UnregisterRequest<'a> {
@@ -27,7 +26,7 @@ impl<'a> UnregisterRequest<'a> {
         }
     }
 
-    pub(crate) async fn send_request(&self, client: &HyperClient) -> Result<Bytes> {
+    pub(crate) async fn send_request(&self, transport: &dyn Transport) -> Result<Bytes> {
         let uri = format!(
             "{REGISTRATION_URI}/projects/{}/registrations/{}",
             self.project_id, self.token
@@ -41,13 +40,13 @@ impl<'a> UnregisterRequest<'a> {
                 "x-goog-firebase-installations-auth",
                 self.installation_auth_token,
             )
-            .body(Either::Right(Empty::<Bytes>::new()))?;
+            .body(Bytes::new())?;
 
-        let response = client.request(request).await?;
+        let response = transport.send(request).await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
+            Ok(response.into_body())
         } else {
             Err(Error::UnsuccessfulResponse(status))
         }
@@ -1,9 +1,8 @@
 use bytes::Bytes;
 use http::{HeaderMap, HeaderValue, Method, Uri, header};
-use http_body_util::{BodyExt as _, Either, Full};
 
-use crate::client::service::USER_AGENT;
-use crate::client::{HyperClient, Request, RequestError};
+use crate::client::service::{Dispatcher, USER_AGENT};
+use crate::client::{Request, RequestError, Server};
 use crate::queries::RawMeliorError;
 use crate::{Error, MeliorError, MeliorQuery, MeliorResponse, Result};
 
@@ -18,7 +17,7 @@ impl MeliorService {
 
     pub(crate) async fn send_query<R: Request>(
         &self,
-        client: &HyperClient,
+        dispatcher: &Dispatcher<'_>,
         query: MeliorQuery<'_, R>,
         headers: HeaderMap<HeaderValue>,
     ) -> Result<R::Response>
@@ -27,7 +26,9 @@ impl MeliorService {
     {
         let body = serde_json::to_vec(&query)?;
 
-        let bytes = self.send_raw(client, Bytes::from(body), &headers).await?;
+        let bytes = self
+            .send_raw(dispatcher, Bytes::from(body), &headers)
+            .await?;
         let response = serde_json::from_slice::<MeliorResponse<R>>(&bytes)
             .inspect_err(|error| tracing::error!(?error, "failed to parse melior response"))?;
 
@@ -53,28 +54,24 @@ impl MeliorService {
 
     async fn send_raw(
         &self,
-        client: &HyperClient,
+        dispatcher: &Dispatcher<'_>,
         body: Bytes,
         headers: &HeaderMap<HeaderValue>,
     ) -> Result<Bytes> {
-        let builder = http::Request::builder()
-            .uri(&self.uri)
-            .method(Method::POST)
-            .header(header::CONTENT_TYPE, "application/json")
-            .header(header::USER_AGENT, &**USER_AGENT);
+        dispatcher
+            .send(Server::Melior, || {
+                let builder = http::Request::builder()
+                    .uri(&self.uri)
+                    .method(Method::POST)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(header::USER_AGENT, &**USER_AGENT);
 
-        let builder = headers
-            .iter()
-            .fold(builder, |builder, (key, value)| builder.header(key, value));
+                let builder = headers
+                    .iter()
+                    .fold(builder, |builder, (key, value)| builder.header(key, value));
 
-        let request = builder.body(Either::Left(Full::new(body)))?;
-        let response = client.request(request).await?;
-
-        let status = response.status();
-        if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
-        } else {
-            Err(Error::UnsuccessfulResponse(status))
-        }
+                Ok(builder.body(body.clone())?)
+            })
+            .await
     }
 }
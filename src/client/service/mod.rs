@@ -4,12 +4,21 @@ mod melior;
 mod root;
 
 use std::sync::LazyLock;
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, Request, header};
+use tokio::time;
 
 #[cfg(feature = "fcm")]
 pub(super) use fcm::FcmService;
 pub(super) use melior::MeliorService;
 pub(super) use root::RootService;
 
+use crate::client::{RateLimiter, RetryPolicy, Server, TimedTransport, Transport as _};
+use crate::{Error, Result};
+
 static USER_AGENT: LazyLock<String> = LazyLock::new(|| {
     format!(
         "{}/{} ({})",
@@ -18,3 +27,64 @@ static USER_AGENT: LazyLock<String> = LazyLock::new(|| {
         os_info::get()
     )
 });
+
+// Sends HTTP requests to the API servers, acquiring the rate limiter before every attempt and
+// retrying transient failures
+pub(super) struct Dispatcher<'a> {
+    pub(super) transport: TimedTransport<'a>,
+    pub(super) rate_limiter: &'a RateLimiter,
+    // `None` if the request must not be retried
+    pub(super) retry_policy: Option<&'a RetryPolicy>,
+}
+impl Dispatcher<'_> {
+    // `request` is called before every attempt, since an `http::Request` can only be sent once
+    async fn send(
+        &self,
+        server: Server,
+        request: impl Fn() -> Result<Request<Bytes>>,
+    ) -> Result<Bytes> {
+        let mut attempt = 1;
+        loop {
+            self.rate_limiter.acquire(server).await?;
+
+            let (error, retry_after) = match self.transport.send(request()?).await {
+                Ok(response) if response.status().is_success() => {
+                    self.rate_limiter.record(server, response.status(), None);
+                    return Ok(response.into_body());
+                }
+                Ok(response) => {
+                    let retry_after = parse_retry_after(response.headers());
+                    self.rate_limiter
+                        .record(server, response.status(), retry_after);
+                    (Error::UnsuccessfulResponse(response.status()), retry_after)
+                }
+                Err(error) => (error, None),
+            };
+
+            let Some(delay) = self
+                .retry_policy
+                .and_then(|policy| policy.delay(attempt, &error, retry_after))
+            else {
+                return Err(error);
+            };
+            tracing::warn!(?error, ?delay, attempt, "request failed, retrying");
+            time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+// The `Retry-After` header contains either a number of seconds or an HTTP date
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?;
+    match value.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => (DateTime::parse_from_rfc2822(value)
+            .ok()?
+            .with_timezone(&Utc)
+            - Utc::now())
+        .to_std()
+        .ok()
+        .or(Some(Duration::ZERO)),
+    }
+}
@@ -1,9 +1,8 @@
 use bytes::{BufMut as _, Bytes, BytesMut};
 use http::{HeaderMap, HeaderValue, Method, Uri, header};
-use http_body_util::{BodyExt as _, Either, Full};
 
-use crate::client::service::USER_AGENT;
-use crate::client::{HyperClient, Request, RequestError};
+use crate::client::service::{Dispatcher, USER_AGENT};
+use crate::client::{Request, RequestError, Server};
 use crate::requests::RawRootError;
 use crate::{Error, Result, RootError, RootRequest, RootResponse};
 
@@ -21,7 +20,7 @@ impl RootService {
 
     pub(crate) async fn send_request<R: Request>(
         &self,
-        client: &HyperClient,
+        dispatcher: &Dispatcher<'_>,
         request: RootRequest<'_, R>,
         attachments: Vec<&[u8]>,
         headers: HeaderMap<HeaderValue>,
@@ -47,7 +46,9 @@ impl RootService {
             payload.put_slice(attachment);
         }
 
-        let bytes = self.send_raw(client, payload.freeze(), &headers).await?;
+        let bytes = self
+            .send_raw(dispatcher, payload.freeze(), &headers)
+            .await?;
 
         match serde_json::from_slice::<RootResponse<R>>(&bytes)
             .inspect_err(|error| tracing::error!(?error, "failed to parse root response"))?
@@ -73,27 +74,23 @@ impl RootService {
 
     async fn send_raw(
         &self,
-        client: &HyperClient,
+        dispatcher: &Dispatcher<'_>,
         body: Bytes,
         headers: &HeaderMap<HeaderValue>,
     ) -> Result<Bytes> {
-        let builder = http::Request::builder()
-            .uri(&self.uri)
-            .method(Method::POST)
-            .header(header::USER_AGENT, &**USER_AGENT);
+        dispatcher
+            .send(Server::Root, || {
+                let builder = http::Request::builder()
+                    .uri(&self.uri)
+                    .method(Method::POST)
+                    .header(header::USER_AGENT, &**USER_AGENT);
 
-        let builder = headers
-            .iter()
-            .fold(builder, |builder, (key, value)| builder.header(key, value));
+                let builder = headers
+                    .iter()
+                    .fold(builder, |builder, (key, value)| builder.header(key, value));
 
-        let request = builder.body(Either::Left(Full::new(body)))?;
-        let response = client.request(request).await?;
-
-        let status = response.status();
-        if status.is_success() {
-            Ok(response.collect().await?.to_bytes())
-        } else {
-            Err(Error::UnsuccessfulResponse(status))
-        }
+                Ok(builder.body(body.clone())?)
+            })
+            .await
     }
 }
@@ -1,10 +1,12 @@
 use std::sync::Arc;
+use std::time::Duration;
 
-use chrono::Utc;
-use tokio::sync::RwLock;
+use chrono::{DateTime, TimeDelta, Utc};
+use tokio::sync::{RwLock, watch};
+use tokio::time;
 
 use crate::client::jwt::{Claims as JwtClaims, Result as JwtResult, decode_token};
-use crate::client::{JwtError, Request};
+use crate::client::{AuthStore, JwtError, Request};
 use crate::models::Auth;
 use crate::queries::auth::RefreshQuery;
 use crate::{Client, Result};
@@ -20,6 +22,13 @@ impl InnerState {
     fn is_auth(&self) -> bool {
         matches!(self, InnerState::Authenticated(_, _))
     }
+
+    fn expires_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            InnerState::Authenticated(_, claims) => Some(claims.expires_at),
+            InnerState::Unauthenticated | InnerState::InvalidToken(_) => None,
+        }
+    }
 }
 
 impl TryFrom<Option<Auth>> for InnerState {
@@ -36,14 +45,43 @@ impl TryFrom<Option<Auth>> for InnerState {
     }
 }
 
+// Why `check_and_refresh()` is called, deciding whether credentials that aren't expiring yet are
+// refreshed anyway
+#[derive(Clone, Copy)]
+enum Trigger<'a> {
+    // Only expiring credentials are refreshed
+    Expiry,
+    // The server has rejected this access token, so it's refreshed unless it has already been
+    // replaced by another caller
+    Rejected(&'a str),
+    // The credentials must be rotated no matter what (e.g., after changing the password)
+    Rotation,
+}
+
+// How long the background task waits before trying again after a failed refresh
+const BACKGROUND_RETRY_DELAY: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 pub(super) struct TokenProvider {
     inner: RwLock<InnerState>,
+    store: Option<Arc<dyn AuthStore>>,
+    refresh_margin: TimeDelta,
+    // Notifies the background task about every state transition. The task stops once this is
+    // dropped together with the client
+    expiry: watch::Sender<Option<DateTime<Utc>>>,
 }
 impl TokenProvider {
-    pub(super) fn new(auth: Option<Auth>) -> JwtResult<Self> {
+    pub(super) fn new(
+        auth: Option<Auth>,
+        store: Option<Arc<dyn AuthStore>>,
+        refresh_margin: Duration,
+    ) -> JwtResult<Self> {
+        let state = InnerState::try_from(auth)?;
         Ok(TokenProvider {
-            inner: RwLock::new(InnerState::try_from(auth)?),
+            expiry: watch::Sender::new(state.expires_at()),
+            inner: RwLock::new(state),
+            store,
+            refresh_margin: TimeDelta::from_std(refresh_margin).unwrap_or(TimeDelta::MAX),
         })
     }
 
@@ -54,9 +92,9 @@ impl TokenProvider {
     pub(super) async fn auth(&self, client: &Client) -> Result<Option<Auth>> {
         let guard = self.inner.read().await;
         match &*guard {
-            InnerState::Authenticated(_, claims) if claims.expires_at < Utc::now() => {
+            InnerState::Authenticated(_, claims) if self.is_expiring(claims) => {
                 drop(guard);
-                self.check_and_refresh(client).await
+                self.check_and_refresh(client, Trigger::Expiry).await
             }
             InnerState::Authenticated(auth, _) => Ok(Some(auth.clone())),
             InnerState::Unauthenticated => Ok(None),
@@ -71,20 +109,116 @@ impl TokenProvider {
     pub(super) async fn set_auth(&self, auth: Option<Auth>) -> JwtResult<()> {
         let mut guard = self.inner.write().await;
         *guard = InnerState::try_from(auth)?;
+        self.transition(&guard);
         Ok(())
     }
 
-    async fn check_and_refresh(&self, client: &Client) -> Result<Option<Auth>> {
+    // Refreshes the credentials even if they haven't expired yet, unless `rejected_token` has
+    // already been replaced by another caller
+    pub(super) async fn force_refresh(
+        &self,
+        client: &Client,
+        rejected_token: &str,
+    ) -> Result<Option<String>> {
+        Ok(self
+            .check_and_refresh(client, Trigger::Rejected(rejected_token))
+            .await?
+            .map(|auth| auth.access_token))
+    }
+
+    // Refreshes the credentials even if they haven't expired yet. The current refresh token is
+    // read while holding the write guard, so it can't be spent by a concurrent refresh
+    pub(super) async fn rotate(&self, client: &Client) -> Result<Option<Auth>> {
+        self.check_and_refresh(client, Trigger::Rotation).await
+    }
+
+    // Spawns a task that refreshes the credentials `refresh_margin` before they expire. The task
+    // only holds a weak reference to the client, so it doesn't keep the client alive
+    pub(super) fn spawn_background_refresh(&self, client: &Client) {
+        let inner = Arc::downgrade(&client.inner);
+        let mut expiry = self.expiry.subscribe();
+        let refresh_margin = self.refresh_margin;
+
+        tokio::spawn(async move {
+            loop {
+                let Some(expires_at) = *expiry.borrow_and_update() else {
+                    if expiry.changed().await.is_err() {
+                        return;
+                    }
+                    continue;
+                };
+
+                let delay = (expires_at - Utc::now())
+                    .checked_sub(&refresh_margin)
+                    .and_then(|delay| delay.to_std().ok())
+                    .unwrap_or_default();
+                tokio::select! {
+                    () = time::sleep(delay) => {}
+                    result = expiry.changed() => {
+                        if result.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                }
+
+                let Some(inner) = inner.upgrade() else {
+                    return;
+                };
+                let client = Client {
+                    inner,
+                    request_timeout: None,
+                };
+                tracing::debug!("refreshing auth in the background");
+                if let Err(error) = client.inner.token_provider.auth(&client).await {
+                    tracing::warn!(?error, "failed to refresh auth in the background");
+                    drop(client);
+
+                    // The state is unchanged after a failed refresh, so wait before trying again
+                    tokio::select! {
+                        () = time::sleep(BACKGROUND_RETRY_DELAY) => {}
+                        result = expiry.changed() => {
+                            if result.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    fn is_expiring(&self, claims: &JwtClaims) -> bool {
+        claims.expires_at - Utc::now() < self.refresh_margin
+    }
+
+    // Concurrent callers share a single refresh: the write guard is held while refreshing, so the
+    // rest of them wait for it and find fresh credentials once they get the guard
+    async fn check_and_refresh(
+        &self,
+        client: &Client,
+        trigger: Trigger<'_>,
+    ) -> Result<Option<Auth>> {
         let mut guard = self.inner.write().await;
 
         // If `refresh_now()` fails, the error is returned immediately without poisoning, so the
         // same request can be sent again later
         let auth = match &*guard {
-            InnerState::Authenticated(auth, claims) if claims.expires_at < Utc::now() => {
-                tracing::debug!(expires_at = ?claims.expires_at, "auth has expired, refreshing");
+            InnerState::Authenticated(auth, claims) if self.is_expiring(claims) => {
+                tracing::debug!(expires_at = ?claims.expires_at, "auth is expiring, refreshing");
                 Self::refresh_now(client, auth).await?
             }
-            InnerState::Authenticated(auth, _) => return Ok(Some(auth.clone())),
+            InnerState::Authenticated(auth, _) => match trigger {
+                Trigger::Rejected(token) if token == auth.access_token => {
+                    tracing::debug!("auth was rejected by the server, refreshing");
+                    Self::refresh_now(client, auth).await?
+                }
+                Trigger::Rotation => {
+                    tracing::debug!("rotating auth");
+                    Self::refresh_now(client, auth).await?
+                }
+                Trigger::Expiry | Trigger::Rejected(_) => return Ok(Some(auth.clone())),
+            },
             InnerState::Unauthenticated => return Ok(None),
             InnerState::InvalidToken(error) => return Err(Arc::clone(error).into()),
         };
@@ -94,6 +228,7 @@ impl TokenProvider {
         *guard = Some(auth)
             .try_into()
             .unwrap_or_else(|error| InnerState::InvalidToken(Arc::new(error)));
+        self.transition(&guard);
         match &*guard {
             InnerState::Authenticated(auth, _) => Ok(Some(auth.clone())),
             InnerState::Unauthenticated => unreachable!("unauthenticated auth state"),
@@ -101,6 +236,24 @@ impl TokenProvider {
         }
     }
 
+    // Must be called with the write guard held, so the store sees the transitions in order
+    fn transition(&self, state: &InnerState) {
+        self.expiry.send_replace(state.expires_at());
+
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        let result = match state {
+            InnerState::Authenticated(auth, _) => store.save(auth),
+            // The stored credentials can't be used anymore once the state is poisoned
+            InnerState::Unauthenticated | InnerState::InvalidToken(_) => store.clear(),
+        };
+        if let Err(error) = result {
+            tracing::error!(?error, "failed to persist auth state");
+        }
+    }
+
     async fn refresh_now(client: &Client, auth: &Auth) -> Result<Auth> {
         Ok(RefreshQuery::new(&auth.refresh_token)
             .send_request(client)
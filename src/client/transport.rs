@@ -0,0 +1,271 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::FutureExt as _;
+use futures::future::{self, BoxFuture};
+use http::{Request, Response};
+use http_body_util::{BodyExt as _, Either, Empty, Full};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::Client as HyperClient;
+use hyper_util::rt::TokioExecutor;
+use tokio::time;
+
+use crate::client::proxy::{Proxy, ProxyConnector};
+use crate::{Error, Result};
+
+/// A transport that sends HTTP requests to the servers and receives their responses.
+///
+/// The client sends every request through its transport, which is [`HyperTransport`] by default.
+/// A custom transport can be set with [`Builder::transport()`][crate::client::Builder::transport()]
+/// to route the traffic through a different HTTP stack, or to serve responses without a network
+/// with [`MemoryTransport`].
+///
+/// Responses with unsuccessful status codes must be returned as usual, since the client handles
+/// them itself (e.g., by retrying the request).
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends the request and returns the response with its whole body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if the request cannot be sent or the response cannot be
+    /// received. Custom transports should return their errors as
+    /// [`Error::TransportError`][crate::Error::TransportError].
+    fn send(&self, request: Request<Bytes>) -> BoxFuture<'_, Result<Response<Bytes>>>;
+}
+
+/// The phase of a request that took too long. See [`Error::Timeout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutPhase {
+    /// Establishing a connection to the server
+    Connect,
+    /// Sending the request and receiving the whole response
+    Request,
+    /// Reading the response body
+    BodyRead,
+}
+
+impl fmt::Display for TimeoutPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Connect => "connect",
+            Self::Request => "request",
+            Self::BodyRead => "body read",
+        })
+    }
+}
+
+type HyperBody = Either<Full<Bytes>, Empty<Bytes>>;
+
+/// The default [`Transport`], which sends requests over HTTP/1 or HTTP/2 with [`hyper`],
+/// verifying TLS certificates against the Mozilla root certificates.
+#[derive(Clone, Debug)]
+pub struct HyperTransport {
+    client: HyperClient<HttpsConnector<ProxyConnector>, HyperBody>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    read_timeout: Option<Duration>,
+}
+impl HyperTransport {
+    /// Creates a new `HyperTransport` without timeouts that connects to the servers directly.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: Self::build_client(None, None),
+            connect_timeout: None,
+            proxy: None,
+            read_timeout: None,
+        }
+    }
+
+    /// Sets the maximum time to establish a connection to the server. Exceeding it fails the
+    /// request with [`Error::Timeout`] in the [`TimeoutPhase::Connect`] phase.
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self.client = Self::build_client(self.proxy.clone(), self.connect_timeout);
+        self
+    }
+
+    /// Sets the proxy to connect to the servers through. See [`Proxy`] for details.
+    #[must_use]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self.client = Self::build_client(self.proxy.clone(), self.connect_timeout);
+        self
+    }
+
+    /// Sets the maximum time to read the response body once the headers have been received.
+    /// Exceeding it fails the request with [`Error::Timeout`] in the [`TimeoutPhase::BodyRead`]
+    /// phase.
+    #[must_use]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    fn build_client(
+        proxy: Option<Proxy>,
+        connect_timeout: Option<Duration>,
+    ) -> HyperClient<HttpsConnector<ProxyConnector>, HyperBody> {
+        let connector = HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_all_versions()
+            .wrap_connector(ProxyConnector::new(proxy, connect_timeout));
+        HyperClient::builder(TokioExecutor::new()).build(connector)
+    }
+
+    // The connector reports a connect timeout as an I/O error somewhere in the error's sources
+    fn map_error(error: hyper_util::client::legacy::Error) -> Error {
+        let mut source = error.source();
+        while let Some(current) = source {
+            if current
+                .downcast_ref::<io::Error>()
+                .is_some_and(|error| error.kind() == ErrorKind::TimedOut)
+            {
+                return Error::Timeout {
+                    phase: TimeoutPhase::Connect,
+                };
+            }
+            source = current.source();
+        }
+
+        error.into()
+    }
+}
+
+impl Default for HyperTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: Request<Bytes>) -> BoxFuture<'_, Result<Response<Bytes>>> {
+        async move {
+            // Requests without a body (e.g., `DELETE` ones) shouldn't send one at all
+            let request = request.map(|body| {
+                if body.is_empty() {
+                    Either::Right(Empty::new())
+                } else {
+                    Either::Left(Full::new(body))
+                }
+            });
+
+            let (parts, body) = self
+                .client
+                .request(request)
+                .await
+                .map_err(Self::map_error)?
+                .into_parts();
+
+            let body = match self.read_timeout {
+                Some(timeout) => {
+                    time::timeout(timeout, body.collect())
+                        .await
+                        .map_err(|_| Error::Timeout {
+                            phase: TimeoutPhase::BodyRead,
+                        })??
+                }
+                None => body.collect().await?,
+            };
+            Ok(Response::from_parts(parts, body.to_bytes()))
+        }
+        .boxed()
+    }
+}
+
+// Bounds the time a whole request takes, regardless of the transport
+#[derive(Debug)]
+pub(super) struct TimedTransport<'a> {
+    pub(super) transport: &'a dyn Transport,
+    pub(super) timeout: Option<Duration>,
+}
+
+impl Transport for TimedTransport<'_> {
+    fn send(&self, request: Request<Bytes>) -> BoxFuture<'_, Result<Response<Bytes>>> {
+        let Some(timeout) = self.timeout else {
+            return self.transport.send(request);
+        };
+
+        time::timeout(timeout, self.transport.send(request))
+            .map(|result| {
+                result.unwrap_or(Err(Error::Timeout {
+                    phase: TimeoutPhase::Request,
+                }))
+            })
+            .boxed()
+    }
+}
+
+type Handler = dyn Fn(&Request<Bytes>) -> Response<Bytes> + Send + Sync;
+
+/// A [`Transport`] that answers requests with a handler function instead of sending them over the
+/// network, which is useful for testing.
+///
+/// The transport also keeps every request it has received, so they can be inspected later with
+/// [`MemoryTransport::take_requests()`]. Clones of a `MemoryTransport` share the same handler and
+/// requests, so a clone can be kept while the original is passed to the client.
+///
+/// # Examples
+///
+/// ```
+/// # use bonfire::ClientBuilder;
+/// use bonfire::client::MemoryTransport;
+/// use bytes::Bytes;
+/// use http::Response;
+///
+/// let transport = MemoryTransport::new(|_request| {
+///     Response::new(Bytes::from_static(br#"{"data":{}}"#))
+/// });
+/// let client = &ClientBuilder::new().transport(transport.clone()).build();
+/// // Send requests using `client`, then inspect them with `transport.take_requests()`
+/// ```
+#[derive(Clone)]
+pub struct MemoryTransport {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<Request<Bytes>>>>,
+}
+impl MemoryTransport {
+    /// Creates a new `MemoryTransport` that answers every request with the response returned by
+    /// `handler`.
+    #[must_use]
+    pub fn new(
+        handler: impl Fn(&Request<Bytes>) -> Response<Bytes> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Removes and returns the requests received so far, in the order they were sent.
+    #[must_use]
+    pub fn take_requests(&self) -> Vec<Request<Bytes>> {
+        let mut requests = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+        std::mem::take(&mut *requests)
+    }
+}
+
+impl fmt::Debug for MemoryTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryTransport")
+            .field("requests", &self.requests)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&self, request: Request<Bytes>) -> BoxFuture<'_, Result<Response<Bytes>>> {
+        let response = (self.handler)(&request);
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(request);
+        future::ready(Ok(response)).boxed()
+    }
+}
@@ -48,39 +48,24 @@
 //! the same session:
 //!
 //! ```no_run
-//! # #[cfg(feature = "serde")]
-//! # mod wrapper {
-//! use std::fs;
-//!
 //! use anyhow::Result;
+//! use bonfire::client::JsonFileStore;
 //! use bonfire::prelude::*;
 //! use futures_util::TryStreamExt as _;
 //!
 //! const EMAIL: &str = "user@example.com";
 //! const PASSWORD: &str = "password";
 //!
-//! async fn save_credentials(client: &Client) -> Result<()> {
-//!     let data = serde_json::to_string(&client.auth().await?)?;
-//!     fs::write("credentials.json", data)?;
-//!     Ok(())
-//! }
-//!
 //! #[tokio::main]
 //! async fn main() -> Result<()> {
-//!     // Build client & authenticate (either by using `credentials.json` or sending a login request)
-//!     let auth_data = fs::read("credentials.json")
-//!         .ok()
-//!         .map(|data| serde_json::from_slice::<Auth>(&data))
-//!         .transpose()?;
-//!     let client = &match auth_data {
-//!         Some(auth) => Client::builder().auth(auth).expect("invalid auth").build(),
-//!         None => {
-//!             let client = Client::default();
-//!             client.login(EMAIL, PASSWORD).await?;
-//!             save_credentials(&client).await?;
-//!             client
-//!         }
-//!     };
+//!     // Build client & authenticate (either by using `credentials.json` or sending a login
+//!     // request). The credentials are saved to `credentials.json` every time they change
+//!     let client = &Client::builder()
+//!         .auth_store(JsonFileStore::new("credentials.json"))?
+//!         .build();
+//!     if !client.is_auth().await {
+//!         client.login(EMAIL, PASSWORD).await?;
+//!     }
 //!
 //!     // Fetch accounts that have "Sus" in their name
 //!     Account::search(client, Some("Sus"), 0)
@@ -90,11 +75,8 @@
 //!         })
 //!         .await?;
 //!
-//!     // Save authentication credentials and exit program
-//!     save_credentials(client).await?;
 //!     Ok(())
 //! }
-//! # }
 //! ```
 
 // `doc_cfg` feature when building documentation for docs.rs
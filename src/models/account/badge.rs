@@ -1,14 +1,85 @@
+use chrono::{DateTime, Utc};
+use futures::{Stream, TryStreamExt as _};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::models::ImageRef;
+use crate::client::Request as _;
+use crate::models::streams::paginated_stream;
+use crate::models::{Account, ImageRef};
+use crate::queries::profile::{BadgeDetailsQuery, ListBadgesQuery};
+use crate::{Client, Result};
 
 /// Represents a badge that can be displayed within a user's profile.
 #[derive(Default, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Badge {
+    /// The unique identifier of this badge
+    pub id: u64,
     /// The index of this badge
+    #[deprecated(note = "use `Badge::id` instead")]
     pub index: i64,
+    /// The name of this badge, or empty if unknown (e.g., for [`Account::active_badge`])
+    pub name: String,
+    /// The description of this badge, or empty if unknown
+    pub description: String,
+    /// The date and time when this badge was received, or `None` if unknown
+    pub created_at: Option<DateTime<Utc>>,
     /// The image representing this badge
     pub image: ImageRef,
 }
+impl Badge {
+    /// Retrieves detailed information about this badge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if an error occurs while sending the request.
+    pub async fn details(&self, client: &Client) -> Result<BadgeDetails> {
+        BadgeDetailsQuery::new(self.id)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
+}
+
+/// Represents detailed information about a badge.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct BadgeDetails {
+    /// The badge itself
+    pub badge: Badge,
+    /// The ID of the fandom this badge is related to, if any
+    pub fandom_id: Option<u64>,
+    /// A link related to this badge, if any
+    pub link: Option<String>,
+    /// The ID of the account which owns this badge
+    pub owner_id: u64,
+    /// The name of the account which owns this badge
+    pub owner_name: String,
+}
+
+impl Account {
+    /// Retrieves a [`Stream`] of badges received by this account.
+    ///
+    /// This method returns a [`Stream`] that yields individual [`Badge`] instances as they are
+    /// retrieved. The stream handles pagination automatically, fetching new pages of results as
+    /// needed. If an [`Error`][crate::Error] occurs during the retrieval of any page, the stream
+    /// will yield that single error and then terminate.
+    pub fn list_badges<'a>(&'a self, client: &'a Client) -> impl Stream<Item = Result<Badge>> + 'a {
+        paginated_stream(
+            move |cursor: Option<String>| async move {
+                ListBadgesQuery::new(self.id, cursor)
+                    .send_request(client)
+                    .await?
+                    .try_into()
+            },
+            None,
+            |badges: &Vec<(Badge, Option<String>)>, _| {
+                badges
+                    .last()
+                    .and_then(|(_, next_cursor)| next_cursor.clone())
+                    .map(Some)
+            },
+        )
+        .map_ok(|(badge, _)| badge)
+    }
+}
@@ -9,7 +9,7 @@ mod profile;
 mod stat;
 
 pub use access_level::AccessLevel;
-pub use badge::Badge;
+pub use badge::{Badge, BadgeDetails};
 pub use ban_entry::BanEntry;
 use chrono::{DateTime, Duration, Utc};
 pub use effect::{
@@ -23,10 +23,12 @@ use serde::{Deserialize, Serialize};
 pub use stat::Stat;
 
 use crate::client::Request as _;
+use crate::models::publication::RateEntry;
 use crate::models::streams::{auto_paginated_stream, paginated_stream};
 use crate::models::{Gender, ImageRef};
 use crate::requests::account::{
-    GetAccountRequest, ListBannedRequest, ListOnlineRequest, ReportRequest, SearchAccountsRequest,
+    GetAccountRequest, ListAccountRatesRequest, ListBannedRequest, ListOnlineRequest,
+    ReportRequest, SearchAccountsRequest,
 };
 use crate::{Client, Result};
 
@@ -224,6 +226,34 @@ impl Account {
         )
     }
 
+    /// Retrieves a [`Stream`] of karma rates placed by this account, from newest to oldest.
+    ///
+    /// The history covers the same rates that are summarized by
+    /// [`Info::rates_count`], [`Info::positive_rates_sum`] and [`Info::negative_rates_sum`].
+    /// Anonymous rates are only included if this account is the authenticated user's account.
+    ///
+    /// This method returns a [`Stream`] that yields individual [`RateEntry`] instances as they are
+    /// retrieved. The stream handles pagination automatically, fetching new pages of results as
+    /// needed. The `offset` parameter can be used to skip a number of rates from the beginning of
+    /// the list. If an [`Error`][crate::Error] occurs during the retrieval of any page, the stream
+    /// will yield that single error and then terminate.
+    pub fn list_rates<'a>(
+        &'a self,
+        client: &'a Client,
+        offset: usize,
+    ) -> impl Stream<Item = Result<RateEntry>> + 'a {
+        auto_paginated_stream(
+            move |offset| async move {
+                ListAccountRatesRequest::new(self.id, offset)
+                    .send_request(client)
+                    .await?
+                    .try_into()
+            },
+            offset,
+            ListAccountRatesRequest::PAGE_SIZE,
+        )
+    }
+
     /// Reports this account.
     ///
     /// # Errors
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while changing the email address.
+#[derive(Error, Debug)]
+pub enum ChangeEmailError {
+    /// The provided email address is invalid
+    #[error("invalid email")]
+    InvalidEmail,
+    /// The provided email address is already registered
+    #[error("email is already taken")]
+    EmailTaken,
+}
+
+impl RequestError for ChangeEmailError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("InvalidEmail", _)) => Some(ChangeEmailError::InvalidEmail),
+            Some(("EmailTaken", _)) => Some(ChangeEmailError::EmailTaken),
+            _ => None,
+        })
+    }
+}
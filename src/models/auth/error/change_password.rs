@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while changing the password.
+#[derive(Error, Debug)]
+pub enum ChangePasswordError {
+    /// The provided old password is incorrect
+    #[error("wrong password")]
+    WrongPassword,
+    /// The provided new password doesn't meet the server's requirements
+    #[error("password is too weak")]
+    WeakPassword,
+}
+
+impl RequestError for ChangePasswordError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("WrongPassword", _)) => Some(ChangePasswordError::WrongPassword),
+            Some(("WeakPassword", _)) => Some(ChangePasswordError::WeakPassword),
+            _ => None,
+        })
+    }
+}
@@ -24,6 +24,9 @@ impl fmt::Display for TfaKind {
 }
 
 /// Represents data required to continue logging in using Two-Factor Authentication (TFA).
+///
+/// Completing a TFA login isn't supported yet, as the server operations for submitting a code or
+/// waiting for an approval aren't among the GraphQL operations this crate is built with.
 #[derive(Debug)]
 pub struct TfaRequired {
     /// The type of this TFA session
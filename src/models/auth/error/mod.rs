@@ -1,7 +1,21 @@
+mod change_email;
+mod change_password;
 mod login_email;
 mod logout;
 mod refresh;
+mod register_email;
+mod resend_verification;
+mod revoke_oauth_grant;
+mod send_password_recovery;
+mod terminate_session;
 
+pub use change_email::ChangeEmailError;
+pub use change_password::ChangePasswordError;
 pub use login_email::{LoginError, TfaKind, TfaRequired};
 pub use logout::LogoutError;
 pub use refresh::RefreshError;
+pub use register_email::RegisterError;
+pub use resend_verification::ResendVerificationError;
+pub use revoke_oauth_grant::RevokeOAuthGrantError;
+pub use send_password_recovery::PasswordRecoveryError;
+pub use terminate_session::TerminateSessionError;
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur during the registration process.
+#[derive(Error, Debug)]
+pub enum RegisterError {
+    /// The provided email address is invalid
+    #[error("invalid email")]
+    InvalidEmail,
+    /// The provided email address is already registered
+    #[error("email is already taken")]
+    EmailTaken,
+    /// The provided password doesn't meet the server's requirements
+    #[error("password is too weak")]
+    WeakPassword,
+}
+
+impl RequestError for RegisterError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("InvalidEmail", _)) => Some(RegisterError::InvalidEmail),
+            Some(("EmailTaken", _)) => Some(RegisterError::EmailTaken),
+            Some(("WeakPassword", _)) => Some(RegisterError::WeakPassword),
+            _ => None,
+        })
+    }
+}
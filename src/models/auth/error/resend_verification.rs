@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while resending the verification email.
+#[derive(Error, Debug)]
+pub enum ResendVerificationError {
+    /// The provided email address is not registered
+    #[error("wrong email")]
+    WrongEmail,
+    /// The email address has already been verified
+    #[error("email is already verified")]
+    AlreadyVerified,
+    /// A verification email was sent too recently
+    #[error("try again later")]
+    TryAgainLater,
+}
+
+impl RequestError for ResendVerificationError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("WrongEmail", _)) => Some(ResendVerificationError::WrongEmail),
+            Some(("AlreadyVerified", _)) => Some(ResendVerificationError::AlreadyVerified),
+            Some(("TryAgainLater", _)) => Some(ResendVerificationError::TryAgainLater),
+            _ => None,
+        })
+    }
+}
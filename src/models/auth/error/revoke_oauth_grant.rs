@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while revoking an OAuth grant.
+#[derive(Error, Debug)]
+pub enum RevokeOAuthGrantError {
+    /// The grant doesn't exist or has already been revoked
+    #[error("grant not found")]
+    NotFound,
+}
+
+impl RequestError for RevokeOAuthGrantError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("NotFound", _)) => Some(RevokeOAuthGrantError::NotFound),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while requesting a password recovery email.
+#[derive(Error, Debug)]
+pub enum PasswordRecoveryError {
+    /// The provided email address is invalid
+    #[error("invalid email")]
+    InvalidEmail,
+    /// The provided email address is not registered
+    #[error("wrong email")]
+    WrongEmail,
+    /// A password recovery email was sent too recently
+    #[error("try again later")]
+    TryAgainLater,
+}
+
+impl RequestError for PasswordRecoveryError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("InvalidEmail", _)) => Some(PasswordRecoveryError::InvalidEmail),
+            Some(("WrongEmail", _)) => Some(PasswordRecoveryError::WrongEmail),
+            Some(("TryAgainLater", _)) => Some(PasswordRecoveryError::TryAgainLater),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{MeliorError, Result};
+
+/// Represents errors that can occur while terminating a session.
+#[derive(Error, Debug)]
+pub enum TerminateSessionError {
+    /// The session is the one the client is currently authenticated with
+    #[error("can't terminate the current session")]
+    CurrentSession,
+    /// The session doesn't exist or has already been terminated
+    #[error("session not found")]
+    NotFound,
+}
+
+impl RequestError for TerminateSessionError {
+    type Source = MeliorError;
+
+    fn try_convert(error: &MeliorError) -> Result<Option<Self>> {
+        Ok(match error.message.split_once(':') {
+            Some(("NotFound", _)) => Some(TerminateSessionError::NotFound),
+            _ => None,
+        })
+    }
+}
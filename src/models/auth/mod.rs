@@ -1,8 +1,12 @@
 mod error;
+mod oauth;
+mod session;
 
 pub use error::*;
+pub use oauth::{OAuthClient, OAuthGrant};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use session::{LinkedLogins, Session};
 
 use crate::client::Request as _;
 use crate::models::Profile;
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request as _;
+use crate::queries::auth::RevokeOAuthGrantQuery;
+use crate::{Client, Result};
+
+/// Represents a third-party application that can be granted access to a user's account.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OAuthClient {
+    /// The unique identifier of this application
+    pub id: String,
+    /// The name of this application shown to users
+    pub name: String,
+    /// Indicates if this application is developed by the Bonfire team
+    pub is_official: bool,
+    /// A link to the terms of service of this application, if any
+    pub tos_url: Option<String>,
+    /// A link to the privacy policy of this application, if any
+    pub privacy_policy_url: Option<String>,
+}
+
+/// Represents access to the current user's account granted to a third-party application.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OAuthGrant {
+    /// The unique identifier of this grant
+    pub id: String,
+    /// The application this grant was given to
+    pub client: OAuthClient,
+    /// The scope of access given to the application
+    pub scope: String,
+    /// The date and time when this grant was given
+    pub created_at: DateTime<Utc>,
+    /// The date and time when the application last used this grant, or `None` if it never did
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+impl OAuthGrant {
+    /// Revokes this grant, preventing the application from accessing the current user's account.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`RevokeOAuthGrantError::NotFound`][crate::models::auth::RevokeOAuthGrantError::NotFound]
+    ///   if this grant doesn't exist or has already been revoked.
+    /// * Returns [`Error`][crate::Error] if any other error occurs while sending the request.
+    pub async fn revoke(&self, client: &Client) -> Result<()> {
+        RevokeOAuthGrantQuery::new(&self.id)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+}
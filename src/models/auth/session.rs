@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request as _;
+use crate::models::auth::TerminateSessionError;
+use crate::queries::auth::TerminateSessionQuery;
+use crate::{Client, Error, Result};
+
+/// Represents an active authentication session of the current user.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Session {
+    /// The unique identifier of this session
+    pub id: String,
+    /// Indicates if this session can still be used to send requests
+    pub is_active: bool,
+    /// The date and time when this session was last used
+    pub last_active_at: DateTime<Utc>,
+    /// The user agent of the device this session was created on
+    pub user_agent: String,
+    /// The IP address this session was last used from
+    pub ip: String,
+    /// Indicates if this session is the one the client is currently authenticated with
+    pub is_current: bool,
+}
+impl Session {
+    /// Terminates this session, logging out the device it belongs to.
+    ///
+    /// The current session can't be terminated with this method, use
+    /// [`Client::logout`][crate::Client::logout] instead.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`TerminateSessionError::CurrentSession`] if this is the current session.
+    /// * Returns [`TerminateSessionError::NotFound`] if this session doesn't exist or has already
+    ///   been terminated.
+    /// * Returns [`Error`] if any other error occurs while sending the request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bonfire::{Client, Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #     let client = &Client::default();
+    /// for session in client.list_sessions().await? {
+    ///     if !session.is_current {
+    ///         session.terminate(client).await?;
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn terminate(&self, client: &Client) -> Result<()> {
+        if self.is_current {
+            return Err(Error::RequestError(Box::new(
+                TerminateSessionError::CurrentSession,
+            )));
+        }
+
+        TerminateSessionQuery::new(&self.id)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Represents the sign-in methods linked to the current user's account.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct LinkedLogins {
+    /// Indicates if a Google account is linked
+    pub is_google_linked: bool,
+    /// Indicates if a legacy Firebase account is linked
+    pub is_firebase_linked: bool,
+}
@@ -0,0 +1,3 @@
+mod send_message;
+
+pub use send_message::SendMessageError;
@@ -0,0 +1,46 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::models::common::ComposeError;
+use crate::{Result, RootError};
+
+/// Represents errors that can occur while sending a chat message.
+#[derive(Error, Debug)]
+pub enum SendMessageError {
+    /// The message has neither text nor attachments, or its text exceeds the maximum allowed
+    /// length
+    #[error("invalid text length")]
+    InvalidTextLength,
+    /// The message contains more images than allowed
+    #[error("too many images")]
+    TooManyImages,
+    /// One of the provided static images exceeds the maximum allowed size
+    #[error("image is too large")]
+    ImageTooLarge,
+    /// The provided GIF exceeds the maximum allowed size
+    #[error("GIF is too large")]
+    GifTooLarge,
+    /// The recipient of a direct message has blocked the sender
+    #[error("blocked by recipient")]
+    Blocked,
+}
+
+impl RequestError for SendMessageError {
+    type Source = RootError;
+
+    fn try_convert(error: &RootError) -> Result<Option<Self>> {
+        Ok(match error {
+            RootError::Other { code, .. } if code == "E_BLACK_LIST" => {
+                Some(SendMessageError::Blocked)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl ComposeError for SendMessageError {
+    const INVALID_TEXT_LENGTH: Self = SendMessageError::InvalidTextLength;
+    const TOO_MANY_IMAGES: Self = SendMessageError::TooManyImages;
+    const IMAGE_TOO_LARGE: Self = SendMessageError::ImageTooLarge;
+    const GIF_TOO_LARGE: Self = SendMessageError::GifTooLarge;
+}
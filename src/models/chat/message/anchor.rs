@@ -0,0 +1,31 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents the position and direction from which a chat's message history is walked.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Anchor {
+    /// Walk backwards, starting from the newest message in the chat
+    #[default]
+    Newest,
+    /// Walk backwards, starting from the message preceding the one with the given ID
+    Before(u64),
+    /// Walk forwards, starting from the message following the one with the given ID
+    After(u64),
+}
+impl Anchor {
+    pub(crate) fn message_id(&self) -> u64 {
+        match self {
+            Self::Newest => 0,
+            Self::Before(id) | Self::After(id) => *id,
+        }
+    }
+
+    pub(crate) fn is_backwards(&self) -> bool {
+        !matches!(self, Self::After(_))
+    }
+}
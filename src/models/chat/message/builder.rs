@@ -0,0 +1,105 @@
+use crate::Result;
+use crate::models::ChatMessage;
+use crate::models::chat::SendMessageError;
+use crate::models::common::{Attachment, AttachmentLimits};
+use crate::models::publication::ChatMessageContent;
+
+const LIMITS: AttachmentLimits = AttachmentLimits {
+    text_length_range: ChatMessage::TEXT_LENGTH_RANGE,
+    image_max_size: ChatMessageContent::IMAGE_MAX_SIZE,
+    gif_max_size: ChatMessageContent::GIF_MAX_SIZE,
+    images_max_count: ChatMessageContent::IMAGES_MAX_COUNT,
+};
+
+/// A builder for composing a chat message to be sent with
+/// [`Chat::send_message()`][crate::models::Chat::send_message].
+///
+/// A message can carry text, a reply to another message and at most one kind of attachment: a
+/// single image, a set of images or a GIF. Setting an attachment replaces the previously set one.
+///
+/// # Examples
+///
+/// ```
+/// # use bonfire::models::chat::MessageBuilder;
+/// let message = MessageBuilder::new().text("Hello, world!").reply_to(1234);
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct Builder<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) reply_to: Option<u64>,
+    pub(crate) attachment: Attachment<'a>,
+    pub(crate) has_new_formatting: bool,
+}
+impl<'a> Builder<'a> {
+    /// Creates a new, empty `MessageBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the text of the message.
+    ///
+    /// The text length must be within [`ChatMessage::TEXT_LENGTH_RANGE`], unless the message has
+    /// an attachment, in which case the text can be empty.
+    #[must_use]
+    pub fn text(mut self, text: &'a str) -> Self {
+        self.text = text;
+        self
+    }
+
+    /// Makes the message a reply to the chat message with the given ID.
+    ///
+    /// The sent message will reference the replied message through
+    /// [`ChatMessage::reply_to`].
+    #[must_use]
+    pub fn reply_to(mut self, id: u64) -> Self {
+        self.reply_to = Some(id);
+        self
+    }
+
+    /// Attaches a single static image to the message.
+    ///
+    /// The image cannot exceed [`IMAGE_MAX_SIZE`][ChatMessageContent::IMAGE_MAX_SIZE] in size.
+    #[must_use]
+    pub fn image(mut self, image: &'a [u8]) -> Self {
+        self.attachment = Attachment::Image(image);
+        self
+    }
+
+    /// Attaches a set of static images to the message.
+    ///
+    /// No more than [`IMAGES_MAX_COUNT`][ChatMessageContent::IMAGES_MAX_COUNT] images can be
+    /// attached, and each one cannot exceed
+    /// [`IMAGE_MAX_SIZE`][ChatMessageContent::IMAGE_MAX_SIZE] in size.
+    #[must_use]
+    pub fn images(mut self, images: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        self.attachment = Attachment::Images(images.into_iter().collect());
+        self
+    }
+
+    /// Attaches an animated GIF to the message, along with its first frame as a static image.
+    ///
+    /// The first frame cannot exceed [`IMAGE_MAX_SIZE`][ChatMessageContent::IMAGE_MAX_SIZE] in
+    /// size, and the GIF itself cannot exceed
+    /// [`GIF_MAX_SIZE`][ChatMessageContent::GIF_MAX_SIZE] in size.
+    #[must_use]
+    pub fn gif(mut self, first_frame: &'a [u8], animated: &'a [u8]) -> Self {
+        self.attachment = Attachment::Gif {
+            first_frame,
+            animated,
+        };
+        self
+    }
+
+    /// Sets whether the message text uses new Markdown formatting. Defaults to `false`.
+    #[must_use]
+    pub fn new_formatting(mut self, has_new_formatting: bool) -> Self {
+        self.has_new_formatting = has_new_formatting;
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        self.attachment
+            .validate::<SendMessageError>(self.text, false, &LIMITS)
+    }
+}
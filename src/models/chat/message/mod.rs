@@ -0,0 +1,101 @@
+mod anchor;
+mod builder;
+
+pub use anchor::Anchor;
+pub use builder::Builder;
+use futures::Stream;
+
+use crate::client::Request as _;
+use crate::models::chat::Messageable as _;
+use crate::models::streams::paginated_stream;
+use crate::models::{Chat, ChatMessage, Publication};
+use crate::requests::chat::{ListMessagesRequest, SendMessageRequest};
+use crate::{Client, Result};
+
+impl Chat {
+    /// Sends a message composed with a [`MessageBuilder`][Builder] to this chat.
+    ///
+    /// This operation requires an authenticated client. The message is validated locally before
+    /// being sent: its text length must be within
+    /// [`ChatMessage::TEXT_LENGTH_RANGE`] (the text may be empty if the message has an attachment),
+    /// and its attachments must respect the limits defined on
+    /// [`ChatMessageContent`][crate::models::publication::ChatMessageContent].
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`SendMessageError::InvalidTextLength`][super::SendMessageError::InvalidTextLength]
+    ///   if the text is empty and there is no attachment, or if the text is too long.
+    /// * Returns [`SendMessageError::TooManyImages`][super::SendMessageError::TooManyImages] if
+    ///   more than [`IMAGES_MAX_COUNT`][crate::models::publication::ChatMessageContent::IMAGES_MAX_COUNT]
+    ///   images are attached.
+    /// * Returns [`SendMessageError::ImageTooLarge`][super::SendMessageError::ImageTooLarge] if a
+    ///   static image (including the first frame of a GIF) is too large.
+    /// * Returns [`SendMessageError::GifTooLarge`][super::SendMessageError::GifTooLarge] if the GIF
+    ///   is too large.
+    /// * Returns [`SendMessageError::Blocked`][super::SendMessageError::Blocked] if the chat is a
+    ///   direct chat and the recipient has blocked the authenticated user.
+    /// * Returns [`RootError::AccessDenied`][crate::RootError::AccessDenied] if the chat is a group
+    ///   the user is not a member of, or if the user has been removed from it.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn send_message(
+        &self,
+        client: &Client,
+        message: &Builder<'_>,
+    ) -> Result<Publication<ChatMessage>> {
+        message.validate()?;
+        SendMessageRequest::new(self.kind.tag(), message)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
+
+    /// Retrieves a [`Stream`] of messages from this chat's history, starting at the given
+    /// [`MessageAnchor`][Anchor].
+    ///
+    /// When walking backwards ([`MessageAnchor::Newest`][Anchor::Newest] or
+    /// [`MessageAnchor::Before`][Anchor::Before]), messages are yielded from newest to oldest until
+    /// the beginning of the chat is reached. When walking forwards
+    /// ([`MessageAnchor::After`][Anchor::After]), messages are yielded from oldest to newest until
+    /// the newest message is reached, which is useful for catching up after being offline. The
+    /// message the anchor points to is never yielded itself. The stream handles pagination
+    /// automatically, using the ID and creation date of the last yielded message as the cursor
+    /// for the next page.
+    ///
+    /// If an [`Error`][crate::Error] occurs during the retrieval of any page, the stream will yield
+    /// that single error and then terminate.
+    pub fn list_messages<'a>(
+        &self,
+        client: &'a Client,
+        anchor: Anchor,
+    ) -> impl Stream<Item = Result<Publication<ChatMessage>>> + 'a {
+        let tag = self.kind.tag();
+
+        paginated_stream(
+            move |(anchor, offset_date)| {
+                let tag = tag.clone();
+                async move {
+                    ListMessagesRequest::new(tag, &anchor, offset_date)
+                        .send_request(client)
+                        .await?
+                        .try_into()
+                }
+            },
+            (anchor, None),
+            |messages, (anchor, _)| {
+                let length = messages.len();
+                (length >= ListMessagesRequest::PAGE_SIZE)
+                    .then(|| messages.last())
+                    .flatten()
+                    .map(|message| {
+                        let next_anchor = if anchor.is_backwards() {
+                            Anchor::Before(message.id)
+                        } else {
+                            Anchor::After(message.id)
+                        };
+
+                        (next_anchor, Some(message.created_at))
+                    })
+            },
+        )
+    }
+}
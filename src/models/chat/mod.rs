@@ -1,10 +1,14 @@
+mod error;
 mod kind;
+mod message;
 mod tag;
 mod typing;
 
 use chrono::{DateTime, Utc};
+pub use error::*;
 use futures::Stream;
 pub use kind::*;
+pub use message::{Anchor as MessageAnchor, Builder as MessageBuilder};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use tag::Tag;
@@ -20,7 +20,9 @@ pub struct Handler {
 impl Handler {
     pub(super) fn spawn(client: &Client, tag: Tag) -> Self {
         let (sender, receiver) = mpsc::channel(1);
-        let client = client.clone();
+        // A notification that takes longer than the period is useless, and waiting for it would
+        // keep the task from handling commands
+        let client = client.with_timeout(NotifyTypingRequest::PERIOD);
         let span = tracing::info_span!("typing_task", ?tag);
 
         let task = async move {
@@ -49,7 +51,9 @@ impl Handler {
         loop {
             tokio::select! {
                 _ = interval.tick(), if !is_paused => {
-                    let _ = request.send_request(client).await;
+                    if let Err(error) = request.send_request(client).await {
+                        tracing::warn!(?error, "failed to send typing notification");
+                    }
                 }
                 command = receiver.recv() => {
                     match command {
@@ -0,0 +1,112 @@
+use std::ops::RangeInclusive;
+
+use crate::client::RequestError;
+use crate::{Error, Result};
+
+// The media attached to a chat message or a comment being composed
+#[derive(Default, Clone, Debug)]
+pub(crate) enum Attachment<'a> {
+    #[default]
+    None,
+    Image(&'a [u8]),
+    Images(Vec<&'a [u8]>),
+    Gif {
+        first_frame: &'a [u8],
+        animated: &'a [u8],
+    },
+}
+
+// The limits a composed chat message or comment is validated against
+pub(crate) struct Limits {
+    pub text_length_range: RangeInclusive<usize>,
+    pub image_max_size: usize,
+    pub gif_max_size: usize,
+    pub images_max_count: usize,
+}
+
+// A request error that a composed chat message or comment is rejected with locally
+pub(crate) trait ComposeError: RequestError + Sized {
+    const INVALID_TEXT_LENGTH: Self;
+    const TOO_MANY_IMAGES: Self;
+    const IMAGE_TOO_LARGE: Self;
+    const GIF_TOO_LARGE: Self;
+}
+
+impl<'a> Attachment<'a> {
+    // Validates the attachment along with the text it's sent with. `has_other_attachment` allows
+    // the text to be empty even without media (e.g., for a sticker)
+    pub(crate) fn validate<E: ComposeError>(
+        &self,
+        text: &str,
+        has_other_attachment: bool,
+        limits: &Limits,
+    ) -> Result<()> {
+        let has_attachment = match self {
+            Attachment::None => has_other_attachment,
+            Attachment::Image(image) => {
+                check_size(image, limits.image_max_size, E::IMAGE_TOO_LARGE)?;
+                true
+            }
+            Attachment::Images(images) => {
+                if images.len() > limits.images_max_count {
+                    return Err(request_error(E::TOO_MANY_IMAGES));
+                }
+                for image in images {
+                    check_size(image, limits.image_max_size, E::IMAGE_TOO_LARGE)?;
+                }
+                !images.is_empty()
+            }
+            Attachment::Gif {
+                first_frame,
+                animated,
+            } => {
+                check_size(first_frame, limits.image_max_size, E::IMAGE_TOO_LARGE)?;
+                check_size(animated, limits.gif_max_size, E::GIF_TOO_LARGE)?;
+                true
+            }
+        };
+
+        // .chars().count() instead of .len() to account for unicode chars
+        let text_length = text.chars().count();
+        let is_valid_length = if has_attachment {
+            text_length <= *limits.text_length_range.end()
+        } else {
+            limits.text_length_range.contains(&text_length)
+        };
+
+        if is_valid_length {
+            Ok(())
+        } else {
+            Err(request_error(E::INVALID_TEXT_LENGTH))
+        }
+    }
+
+    // The first two attachments of a request are always reserved for a static image (or the
+    // first frame of a GIF) and the GIF itself, the rest are images of an image set
+    pub(crate) fn to_request_attachments(&self) -> Vec<&'a [u8]> {
+        match self {
+            Attachment::None => Vec::new(),
+            Attachment::Image(image) => vec![*image, &[]],
+            Attachment::Images(images) => [&[][..], &[]]
+                .into_iter()
+                .chain(images.iter().copied())
+                .collect(),
+            Attachment::Gif {
+                first_frame,
+                animated,
+            } => vec![*first_frame, *animated],
+        }
+    }
+}
+
+fn check_size<E: ComposeError>(data: &[u8], max_size: usize, error: E) -> Result<()> {
+    if data.len() > max_size {
+        Err(request_error(error))
+    } else {
+        Ok(())
+    }
+}
+
+fn request_error<E: ComposeError>(error: E) -> Error {
+    Error::RequestError(Box::new(error))
+}
@@ -1,8 +1,10 @@
+mod attachment;
 mod category;
 mod image;
 mod language;
 mod voice;
 
+pub(crate) use attachment::{Attachment, ComposeError, Limits as AttachmentLimits};
 pub use category::Category;
 pub use image::ImageRef;
 pub use language::Language;
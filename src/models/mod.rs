@@ -17,7 +17,7 @@ pub mod profile;
 pub mod publication;
 /// Contains structs and helper types for user settings.
 pub mod settings;
-mod streams;
+pub(crate) mod streams;
 
 pub use account::{AccessLevel, Account, Badge, Effect, Info as AccountInfo, Stat as AccountStat};
 pub use auth::Auth;
@@ -26,7 +26,7 @@ pub use common::{Category, ImageRef, Language, VoiceRef};
 pub use fandom::Fandom;
 pub use other::{Config, FirebaseConfig, InitialData};
 #[cfg(feature = "fcm")]
-pub use other::{FcmAndroidRegistration, FcmCredentials};
+pub use other::{FcmAndroidRegistration, FcmCredentials, FcmMessage};
 pub use profile::{Gender, Link, Profile};
 pub use publication::{AnyPublication, ChatMessage, Comment, Post, PostTag, Publication, Reaction};
 pub use settings::Settings;
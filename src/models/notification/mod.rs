@@ -1,3 +1,7 @@
 mod filter;
+#[cfg(feature = "fcm")]
+mod push_event;
 
 pub use filter::Filter;
+#[cfg(feature = "fcm")]
+pub use push_event::PushEvent;
@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::models::publication::Kind;
+use crate::models::{Account, ChatMessage, Publication};
+
+/// Represents an event delivered through a push notification.
+///
+/// Push events are obtained by decrypting a received [`FcmMessage`][crate::models::FcmMessage]
+/// with [`FcmCredentials::decrypt()`][crate::models::FcmCredentials::decrypt]. Accounts embedded in
+/// push events only have their [`id`][Account::id], [`name`][Account::name],
+/// [`avatar`][Account::avatar] and [`gender`][Account::gender] fields set.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(docsrs, doc(cfg(feature = "fcm")))]
+pub enum PushEvent {
+    /// A new message was sent to a chat the user is subscribed to
+    ChatMessage(Box<Publication<ChatMessage>>),
+    /// A new comment was left under the user's publication
+    Comment {
+        /// The ID of the commented publication
+        publication_id: u64,
+        /// The kind of the commented publication
+        publication_kind: Kind,
+        /// The ID of the new comment
+        comment_id: u64,
+        /// The text of the new comment, if any
+        text: Option<String>,
+        /// The author of the new comment
+        author: Account,
+    },
+    /// A new reply was left to the user's comment
+    Reply {
+        /// The ID of the publication the comments are under
+        publication_id: u64,
+        /// The kind of the publication the comments are under
+        publication_kind: Kind,
+        /// The ID of the reply
+        comment_id: u64,
+        /// The text of the reply, if any
+        text: Option<String>,
+        /// The text of the user's comment being replied to, if any
+        parent_text: Option<String>,
+        /// The author of the reply
+        author: Account,
+    },
+    /// The user's publication was rated
+    Rate {
+        /// The ID of the rated publication
+        publication_id: u64,
+        /// The kind of the rated publication
+        publication_kind: Kind,
+        /// The amount of karma the rate has brought. Negative for downvotes
+        karma: f64,
+        /// The account that rated the publication, or `None` if the rate is anonymous
+        rater: Option<Account>,
+    },
+    /// The user was followed by another account
+    Follow {
+        /// The account that followed the user
+        follower: Account,
+    },
+    /// The user has reached a new level of an achievement
+    Achievement {
+        /// The index of the achievement
+        index: i64,
+        /// The reached level of the achievement
+        level: i64,
+    },
+    /// An unknown event type
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Unknown(i64),
+}
@@ -1,7 +1,18 @@
+use base64::Engine as _;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use ece::EcKeyComponents;
+use ece::legacy::AesGcmEncryptedBlock;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::AndroidRegistration;
+use super::{AndroidRegistration, Message};
+use crate::client::FcmError;
+use crate::models::notification::PushEvent;
+use crate::requests::{RawPushEvent, RawPushPayload};
+use crate::{Error, Result};
+
+// The record size used by the legacy `aesgcm` encoding if the `rs` parameter is omitted
+const AESGCM_DEFAULT_RECORD_SIZE: u32 = 4096;
 
 /// Represents FCM credentials needed for receiving push notifications.
 ///
@@ -22,3 +33,97 @@ pub struct Credentials {
     /// The Android registration data
     pub android: AndroidRegistration,
 }
+impl Credentials {
+    /// Decrypts a push notification received through a
+    /// [`PushListener`][crate::client::PushListener] and parses it into a [`PushEvent`].
+    ///
+    /// Both the `aes128gcm` and the legacy `aesgcm` web push encodings are supported. Messages
+    /// that carry the notification unencrypted in their application data are parsed as is.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`FcmError::InvalidPushMessage`] if the message has no payload or lacks the
+    ///   encryption parameters.
+    /// * Returns [`FcmError::EceError`] if the payload cannot be decrypted with these credentials.
+    /// * Returns [`Error::JsonError`] if the decrypted payload is not a valid notification.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the conversion.
+    pub fn decrypt(&self, message: &Message) -> Result<PushEvent> {
+        let notification = match (&message.raw_data, message.app_data.get("my_data")) {
+            (None, Some(notification)) => notification.clone(),
+            (None, None) => {
+                return Err(invalid_message("the message has no payload"));
+            }
+            (Some(data), _) => {
+                let payload = serde_json::from_slice::<RawPushPayload>(&self.decrypt_raw(
+                    data,
+                    message.app_data.get("crypto-key"),
+                    message.app_data.get("encryption"),
+                )?)?;
+                payload.data.my_data
+            }
+        };
+
+        serde_json::from_str::<RawPushEvent>(&notification)?.try_into()
+    }
+
+    fn decrypt_raw(
+        &self,
+        data: &[u8],
+        crypto_key: Option<&String>,
+        encryption: Option<&String>,
+    ) -> Result<Vec<u8>> {
+        let components =
+            EcKeyComponents::new(decode(&self.private_key)?, decode(&self.public_key)?);
+        let auth_secret = decode(&self.auth_secret)?;
+
+        // Messages encoded with the legacy `aesgcm` encoding carry the sender's public key and
+        // the salt in separate parameters, while `aes128gcm` ones have them in the payload
+        Ok(match (crypto_key, encryption) {
+            (Some(crypto_key), Some(encryption)) => {
+                let dh = parameter(crypto_key, "dh")
+                    .ok_or_else(|| invalid_message("the `dh` parameter is missing"))?;
+                let salt = parameter(encryption, "salt")
+                    .ok_or_else(|| invalid_message("the `salt` parameter is missing"))?;
+                let record_size = match parameter(encryption, "rs") {
+                    Some(rs) => rs
+                        .parse()
+                        .map_err(|_| invalid_message("the `rs` parameter is invalid"))?,
+                    None => AESGCM_DEFAULT_RECORD_SIZE,
+                };
+
+                let block = AesGcmEncryptedBlock::new(
+                    &decode(dh)?,
+                    &decode(salt)?,
+                    record_size,
+                    data.to_vec(),
+                )
+                .map_err(FcmError::from)?;
+                ece::legacy::decrypt_aesgcm(&components, &auth_secret, &block)
+                    .map_err(FcmError::from)?
+            }
+            _ => ece::decrypt(&components, &auth_secret, data).map_err(FcmError::from)?,
+        })
+    }
+}
+
+// Finds a `key=value` parameter in a `;`-separated list
+fn parameter<'a>(parameters: &'a str, key: &str) -> Option<&'a str> {
+    parameters.split(';').find_map(|parameter| {
+        parameter
+            .trim()
+            .split_once('=')
+            .filter(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+    })
+}
+
+fn decode(value: &str) -> Result<Vec<u8>> {
+    // Some senders pad their base64 values, even though web push requires them not to
+    Ok(BASE64_URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(FcmError::from)?)
+}
+
+fn invalid_message(message: &str) -> Error {
+    FcmError::InvalidPushMessage(message.to_owned()).into()
+}
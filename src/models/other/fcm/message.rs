@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a data message received from FCM through a
+/// [`PushListener`][crate::client::PushListener].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "fcm")))]
+pub struct Message {
+    /// The persistent ID assigned to this message by FCM, if any. It is acknowledged
+    /// automatically by the listener
+    pub persistent_id: Option<String>,
+    /// The sender ID of the message
+    pub from: String,
+    /// The category (application package) the message is addressed to
+    pub category: String,
+    /// The key-value application data attached to the message
+    pub app_data: HashMap<String, String>,
+    /// The binary payload of the message, if any. For web push messages, this is the encrypted
+    /// payload
+    pub raw_data: Option<Vec<u8>>,
+    /// The timestamp of when the message was sent, if known
+    pub sent_at: Option<DateTime<Utc>>,
+}
@@ -1,5 +1,7 @@
 mod android_registration;
 mod credentials;
+mod message;
 
 pub use android_registration::AndroidRegistration;
 pub use credentials::Credentials;
+pub use message::Message;
@@ -5,5 +5,8 @@ mod initial_data;
 
 pub use config::{Config, Firebase as FirebaseConfig};
 #[cfg(feature = "fcm")]
-pub use fcm::{AndroidRegistration as FcmAndroidRegistration, Credentials as FcmCredentials};
+pub use fcm::{
+    AndroidRegistration as FcmAndroidRegistration, Credentials as FcmCredentials,
+    Message as FcmMessage,
+};
 pub use initial_data::InitialData;
@@ -12,9 +12,9 @@ pub use link::Link;
 use serde::{Deserialize, Serialize};
 
 use crate::client::Request as _;
-use crate::models::ImageRef;
-use crate::queries::auth::GetProfileQuery;
-use crate::queries::profile::SetBirthdayQuery;
+use crate::models::{Badge, ImageRef};
+use crate::queries::auth::{ChangeEmailQuery, GetProfileQuery};
+use crate::queries::profile::{SetBadgeShelfQuery, SetBadgeShelfVisibleQuery, SetBirthdayQuery};
 use crate::requests::account::SetReferrerRequest;
 use crate::requests::account::profile::{
     SetAgeRequest, SetAvatarRequest, SetBackgroundRequest, SetDescriptionRequest, SetNameRequest,
@@ -132,6 +132,53 @@ impl Profile {
             .try_into()
     }
 
+    /// Changes the authenticated user's email address.
+    ///
+    /// The new email address must be verified through the link sent to it before it can be used
+    /// to log in.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`ChangeEmailError::InvalidEmail`][crate::models::auth::ChangeEmailError::InvalidEmail]
+    ///   if the provided email address is invalid.
+    /// * Returns [`ChangeEmailError::EmailTaken`][crate::models::auth::ChangeEmailError::EmailTaken]
+    ///   if the provided email address is already registered.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn change_email(client: &Client, new_email: &str) -> Result<()> {
+        ChangeEmailQuery::new(new_email)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the badges displayed on the authenticated user's badge shelf, in the given order,
+    /// returning the resulting shelf.
+    ///
+    /// The badges are identified by [`Badge::id`]. An empty slice clears the shelf.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if an error occurs while sending the request.
+    pub async fn set_badge_shelf(client: &Client, badge_ids: &[u64]) -> Result<Vec<Badge>> {
+        SetBadgeShelfQuery::new(badge_ids)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
+
+    /// Sets whether the authenticated user's badge shelf is shown to other users, returning the
+    /// current shelf.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if an error occurs while sending the request.
+    pub async fn set_badge_shelf_visible(client: &Client, visible: bool) -> Result<Vec<Badge>> {
+        SetBadgeShelfVisibleQuery::new(visible)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
+
     /// Sets the account's age.
     ///
     /// The age must be within the [`AGE_RANGE`][Self::AGE_RANGE]. A value of `0` or `None`
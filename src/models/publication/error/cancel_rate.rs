@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{Result, RootError};
+
+/// Represents errors that can occur while cancelling a rate placed on a publication.
+#[derive(Error, Debug)]
+pub enum CancelRateError {
+    /// The publication hasn't been rated by the user
+    #[error("not rated")]
+    NotRated,
+    /// The rate was placed too long ago to be cancelled
+    #[error("cancel time expired")]
+    TimeExpired,
+}
+
+impl RequestError for CancelRateError {
+    type Source = RootError;
+
+    fn try_convert(error: &RootError) -> Result<Option<Self>> {
+        Ok(match error {
+            RootError::Other { code, .. } if code == "E_NOT_EXIST" => {
+                Some(CancelRateError::NotRated)
+            }
+            RootError::Other { code, .. } if code == "E_TIME_EXPIRED" => {
+                Some(CancelRateError::TimeExpired)
+            }
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::models::common::ComposeError;
+use crate::{Result, RootError};
+
+/// Represents errors that can occur while posting a comment.
+#[derive(Error, Debug)]
+pub enum CreateCommentError {
+    /// The comment has neither text nor attachments, or its text exceeds the maximum allowed
+    /// length
+    #[error("invalid text length")]
+    InvalidTextLength,
+    /// The comment contains more images than allowed
+    #[error("too many images")]
+    TooManyImages,
+    /// One of the provided static images exceeds the maximum allowed size
+    #[error("image is too large")]
+    ImageTooLarge,
+    /// The provided GIF exceeds the maximum allowed size
+    #[error("GIF is too large")]
+    GifTooLarge,
+    /// The author of the publication has blocked the commenter
+    #[error("blocked by author")]
+    Blocked,
+}
+
+impl RequestError for CreateCommentError {
+    type Source = RootError;
+
+    fn try_convert(error: &RootError) -> Result<Option<Self>> {
+        Ok(match error {
+            RootError::Other { code, .. } if code == "E_BAD_TEXT_SIZE" => {
+                Some(CreateCommentError::InvalidTextLength)
+            }
+            RootError::Other { code, .. } if code == "E_BLACK_LIST" => {
+                Some(CreateCommentError::Blocked)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl ComposeError for CreateCommentError {
+    const INVALID_TEXT_LENGTH: Self = CreateCommentError::InvalidTextLength;
+    const TOO_MANY_IMAGES: Self = CreateCommentError::TooManyImages;
+    const IMAGE_TOO_LARGE: Self = CreateCommentError::ImageTooLarge;
+    const GIF_TOO_LARGE: Self = CreateCommentError::GifTooLarge;
+}
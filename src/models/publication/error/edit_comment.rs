@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{Result, RootError};
+
+/// Represents errors that can occur while editing a comment.
+#[derive(Error, Debug)]
+pub enum EditCommentError {
+    /// The new text is empty or exceeds the maximum allowed length
+    #[error("invalid text length")]
+    InvalidTextLength,
+    /// The comment can no longer be edited, as too much time has passed since it was posted
+    #[error("edit time expired")]
+    TimeExpired,
+}
+
+impl RequestError for EditCommentError {
+    type Source = RootError;
+
+    fn try_convert(error: &RootError) -> Result<Option<Self>> {
+        Ok(match error {
+            RootError::Other { code, .. } if code == "E_BAD_TEXT_SIZE" => {
+                Some(EditCommentError::InvalidTextLength)
+            }
+            RootError::Other { code, .. } if code == "E_TIME_EXPIRED" => {
+                Some(EditCommentError::TimeExpired)
+            }
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,9 @@
+mod cancel_rate;
+mod create_comment;
+mod edit_comment;
+mod rate;
+
+pub use cancel_rate::CancelRateError;
+pub use create_comment::CreateCommentError;
+pub use edit_comment::EditCommentError;
+pub use rate::RateError;
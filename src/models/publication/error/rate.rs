@@ -0,0 +1,42 @@
+use thiserror::Error;
+
+use crate::client::RequestError;
+use crate::{Result, RootError};
+
+/// Represents errors that can occur while rating a publication.
+#[derive(Error, Debug)]
+pub enum RateError {
+    /// The publication has already been rated by the user
+    #[error("already rated")]
+    AlreadyRated,
+    /// The user attempted to rate their own publication
+    #[error("cannot rate own publication")]
+    OwnPublication,
+    /// The user has placed too many rates recently
+    #[error("rate limited")]
+    RateLimited,
+    /// The user has reached the daily limit of karma that can be placed on the publication's
+    /// author
+    #[error("karma cap reached")]
+    KarmaCapReached,
+}
+
+impl RequestError for RateError {
+    type Source = RootError;
+
+    fn try_convert(error: &RootError) -> Result<Option<Self>> {
+        Ok(match error {
+            RootError::Other { code, .. } if code == "E_ALREADY_EXIST" => {
+                Some(RateError::AlreadyRated)
+            }
+            RootError::Other { code, .. } if code == "E_SELF_PUBLICATION" => {
+                Some(RateError::OwnPublication)
+            }
+            RootError::Other { code, .. } if code == "E_RATE_LIMIT" => Some(RateError::RateLimited),
+            RootError::Other { code, .. } if code == "E_KARMA_CAP" => {
+                Some(RateError::KarmaCapReached)
+            }
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Comment, Content};
+use crate::Result;
+use crate::models::common::{Attachment, AttachmentLimits};
+use crate::models::publication::CreateCommentError;
+
+const LIMITS: AttachmentLimits = AttachmentLimits {
+    text_length_range: Comment::TEXT_LENGTH_RANGE,
+    image_max_size: Content::IMAGE_MAX_SIZE,
+    gif_max_size: Content::GIF_MAX_SIZE,
+    images_max_count: Content::IMAGES_MAX_COUNT,
+};
+
+/// A builder for composing a comment to be posted with
+/// [`Publication<Post>::comment()`][crate::models::Publication::comment].
+///
+/// A comment can carry text, a reply to another comment and at most one kind of attachment,
+/// matching the [`CommentContent`][Content] variants: a single image, a set of images, a GIF or a
+/// sticker. Setting an attachment replaces the previously set one.
+///
+/// # Examples
+///
+/// ```
+/// # use bonfire::models::publication::CommentBuilder;
+/// let comment = CommentBuilder::new().text("Great post!").reply_to(1234);
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct Builder<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) reply_to: Option<u64>,
+    pub(crate) attachment: Attachment<'a>,
+    pub(crate) sticker_id: Option<u64>,
+    pub(crate) has_new_formatting: bool,
+}
+impl<'a> Builder<'a> {
+    /// Creates a new, empty `CommentBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the text of the comment.
+    ///
+    /// The text length must be within [`Comment::TEXT_LENGTH_RANGE`], unless the comment has an
+    /// attachment, in which case the text can be empty.
+    #[must_use]
+    pub fn text(mut self, text: &'a str) -> Self {
+        self.text = text;
+        self
+    }
+
+    /// Makes the comment a reply to the comment with the given ID.
+    ///
+    /// The posted comment will reference the replied comment through [`Comment::reply_to`].
+    #[must_use]
+    pub fn reply_to(mut self, id: u64) -> Self {
+        self.reply_to = Some(id);
+        self
+    }
+
+    /// Attaches a single static image to the comment.
+    ///
+    /// The image cannot exceed [`IMAGE_MAX_SIZE`][Content::IMAGE_MAX_SIZE] in size.
+    #[must_use]
+    pub fn image(mut self, image: &'a [u8]) -> Self {
+        self.attachment = Attachment::Image(image);
+        self.sticker_id = None;
+        self
+    }
+
+    /// Attaches a set of static images to the comment.
+    ///
+    /// No more than [`IMAGES_MAX_COUNT`][Content::IMAGES_MAX_COUNT] images can be attached, and
+    /// each one cannot exceed [`IMAGE_MAX_SIZE`][Content::IMAGE_MAX_SIZE] in size.
+    #[must_use]
+    pub fn images(mut self, images: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        self.attachment = Attachment::Images(images.into_iter().collect());
+        self.sticker_id = None;
+        self
+    }
+
+    /// Attaches an animated GIF to the comment, along with its first frame as a static image.
+    ///
+    /// The first frame cannot exceed [`IMAGE_MAX_SIZE`][Content::IMAGE_MAX_SIZE] in size, and the
+    /// GIF itself cannot exceed [`GIF_MAX_SIZE`][Content::GIF_MAX_SIZE] in size.
+    #[must_use]
+    pub fn gif(mut self, first_frame: &'a [u8], animated: &'a [u8]) -> Self {
+        self.attachment = Attachment::Gif {
+            first_frame,
+            animated,
+        };
+        self.sticker_id = None;
+        self
+    }
+
+    /// Attaches the sticker with the given ID to the comment.
+    #[must_use]
+    pub fn sticker(mut self, id: u64) -> Self {
+        self.attachment = Attachment::None;
+        self.sticker_id = Some(id);
+        self
+    }
+
+    /// Sets whether the comment text uses new Markdown formatting. Defaults to `false`.
+    #[must_use]
+    pub fn new_formatting(mut self, has_new_formatting: bool) -> Self {
+        self.has_new_formatting = has_new_formatting;
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        self.attachment.validate::<CreateCommentError>(
+            self.text,
+            self.sticker_id.is_some(),
+            &LIMITS,
+        )
+    }
+}
@@ -1,18 +1,29 @@
+mod builder;
 mod content;
 mod ref_content;
 mod reference;
+mod thread;
 
 use std::ops::RangeInclusive;
 
+pub use builder::Builder;
 pub use content::Content;
+use futures::Stream;
 pub use ref_content::RefContent;
 pub use reference::Reference;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use thread::Thread;
 
-use crate::models::publication::{Kind, Publishable};
-use crate::models::{Account, Fandom};
+use crate::client::Request as _;
+use crate::models::publication::{EditCommentError, Kind, Publishable};
+use crate::models::streams::auto_paginated_stream;
+use crate::models::{Account, Fandom, Publication};
+use crate::requests::publication::comment::{
+    EditCommentRequest, ListCommentsRequest, RemoveCommentRequest,
+};
 use crate::sealed::Sealed;
+use crate::{Client, Error, Result};
 
 /// Represents the specific data for a comment publication, containing text, and optionally a media
 /// [`CommentContent`][Content] or a [`CommentRef`][Reference] to another publication.
@@ -58,3 +69,90 @@ impl Publishable for Comment {
 }
 
 impl Sealed for Comment {}
+
+impl<T: Publishable> Publication<T> {
+    /// Retrieves a [`Stream`] of comments under this publication, from oldest to newest.
+    ///
+    /// This method returns a [`Stream`] that yields individual [`Publication<Comment>`] instances
+    /// as they are retrieved. The stream handles pagination automatically, fetching new pages of
+    /// results as needed. The `offset` parameter can be used to skip a number of comments from the
+    /// beginning of the list. If an [`Error`][crate::Error] occurs during the retrieval of any
+    /// page, the stream will yield that single error and then terminate.
+    ///
+    /// The comments are returned as a flat list; use [`CommentThread::build()`][Thread::build] to
+    /// assemble them into reply threads.
+    pub fn list_comments<'a>(
+        &self,
+        client: &'a Client,
+        offset: usize,
+    ) -> impl Stream<Item = Result<Publication<Comment>>> + 'a {
+        let (id, kind) = (self.id, self.kind.kind());
+        auto_paginated_stream(
+            move |offset| {
+                let kind = kind.clone();
+                async move {
+                    ListCommentsRequest::new(id, kind, offset)
+                        .send_request(client)
+                        .await?
+                        .try_into()
+                }
+            },
+            offset,
+            ListCommentsRequest::PAGE_SIZE,
+        )
+    }
+}
+
+impl Publication<Comment> {
+    /// Replaces the text of this comment, returning the updated comment with
+    /// [`Comment::is_edited`] set.
+    ///
+    /// This operation requires an authenticated client, and only the author of a comment can edit
+    /// it. The new text length must be within [`Comment::TEXT_LENGTH_RANGE`], unless the comment
+    /// has non-text [`CommentContent`][Content], in which case the text can be empty. This is
+    /// validated locally before sending the request.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`EditCommentError::InvalidTextLength`] if the new text is empty (for a text-only
+    ///   comment) or too long.
+    /// * Returns [`EditCommentError::TimeExpired`] if the comment can no longer be edited.
+    /// * Returns [`RootError::AccessDenied`][crate::RootError::AccessDenied] if the authenticated
+    ///   user is not the author of the comment.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn edit(&self, client: &Client, text: &str) -> Result<Self> {
+        // .chars().count() instead of .len() to account for unicode chars
+        let text_length = text.chars().count();
+        let is_valid_length = match self.kind.content {
+            Content::Text => Comment::TEXT_LENGTH_RANGE.contains(&text_length),
+            _ => text_length <= *Comment::TEXT_LENGTH_RANGE.end(),
+        };
+        if !is_valid_length {
+            return Err(Error::RequestError(Box::new(
+                EditCommentError::InvalidTextLength,
+            )));
+        }
+
+        EditCommentRequest::new(self.id, text)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
+
+    /// Deletes this comment.
+    ///
+    /// This operation requires an authenticated client, and only the author of a comment can delete
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RootError::AccessDenied`][crate::RootError::AccessDenied] if the authenticated
+    /// user is not the author of the comment, or [`Error`][crate::Error] if any other error occurs
+    /// during the request.
+    pub async fn delete(&self, client: &Client) -> Result<()> {
+        RemoveCommentRequest::new(self.id)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Comment;
+use crate::models::Publication;
+
+/// Represents a comment together with the replies to it, forming a reply tree.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Thread {
+    /// The comment at the root of this thread
+    pub comment: Publication<Comment>,
+    /// The threads of comments replying to [`Thread::comment`], from oldest to newest
+    pub replies: Vec<Thread>,
+}
+impl Thread {
+    /// Assembles a flat list of comments into reply threads.
+    ///
+    /// A comment is placed under the comment referenced by its [`Comment::reply_to`]. Comments
+    /// that aren't replies, or whose referenced comment isn't in the list (e.g., because it was
+    /// removed), become the roots of the returned threads. The comments may belong to
+    /// publications of any [`Kind`][crate::models::publication::Kind]; comments of different
+    /// publications are never placed in the same thread.
+    ///
+    /// Both the returned threads and their replies are sorted from oldest to newest.
+    pub fn build(comments: impl IntoIterator<Item = Publication<Comment>>) -> Vec<Self> {
+        let mut comments = comments.into_iter().collect::<Vec<_>>();
+        comments.sort_by_key(|comment| (comment.created_at, comment.id));
+
+        let key = |comment: &Publication<Comment>| {
+            (
+                comment.kind.parent_id,
+                comment.kind.parent_kind.clone(),
+                comment.id,
+            )
+        };
+        let parent_key = |comment: &Publication<Comment>| {
+            comment.kind.reply_to.as_ref().map(|reply_to| {
+                (
+                    comment.kind.parent_id,
+                    comment.kind.parent_kind.clone(),
+                    reply_to.id,
+                )
+            })
+        };
+
+        // Replies are attached from newest to oldest, so that every reply is complete before it's
+        // moved into its parent (and its replies only need to be reversed at that point). A reply
+        // can only reference an older comment, which also rules out cycles
+        let mut threads = comments
+            .into_iter()
+            .map(|comment| {
+                (
+                    key(&comment),
+                    Thread {
+                        comment,
+                        replies: Vec::new(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut indices = threads
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key.clone(), index))
+            .collect::<HashMap<_, _>>();
+
+        let mut roots = Vec::new();
+        while let Some((key, mut thread)) = threads.pop() {
+            indices.remove(&key);
+            thread.replies.reverse();
+
+            match parent_key(&thread.comment).and_then(|parent_key| indices.get(&parent_key)) {
+                Some(&index) => threads[index].1.replies.push(thread),
+                None => roots.push(thread),
+            }
+        }
+
+        roots.reverse();
+        roots
+    }
+}
@@ -10,15 +10,16 @@ pub use chat_message::{
     Reference as ChatMessageRef,
 };
 pub use comment::{
-    Comment, Content as CommentContent, RefContent as CommentRefContent, Reference as CommentRef,
+    Builder as CommentBuilder, Comment, Content as CommentContent, RefContent as CommentRefContent,
+    Reference as CommentRef, Thread as CommentThread,
 };
-pub use post::Post;
+pub use post::{Item as PostItem, Post, TableCell as PostTableCell};
 pub use post_tag::PostTag;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Represents the specific type of a publication.
-#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Deserialize, Serialize),
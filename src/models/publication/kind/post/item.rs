@@ -0,0 +1,126 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::models::ImageRef;
+
+/// Represents a single content item (page) of a post.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Item {
+    /// A paragraph of text
+    Text(String),
+    /// A title, displayed in a larger font
+    Title(String),
+    /// A single static image
+    Image(ImageRef),
+    /// An animated GIF image
+    Gif {
+        /// The first frame of the GIF as a static image
+        first_frame: ImageRef,
+        /// The animated GIF itself
+        animated: ImageRef,
+    },
+    /// A gallery of multiple static images
+    Images {
+        /// The images of the gallery
+        images: Vec<ImageRef>,
+        /// The title of the gallery, if any
+        title: Option<String>,
+    },
+    /// A link to an external resource
+    Link {
+        /// The displayed name of the link
+        name: String,
+        /// The URI the link points to
+        uri: String,
+        /// The preview image of the link, if any
+        image: Option<ImageRef>,
+    },
+    /// A quote
+    Quote {
+        /// The author of the quote, if specified
+        author: Option<String>,
+        /// The quoted text
+        text: String,
+    },
+    /// A spoiler that hides the items following it
+    Spoiler {
+        /// The displayed name of the spoiler
+        name: String,
+        /// The number of items following the spoiler that are hidden by it
+        items_count: u64,
+    },
+    /// A link to a video
+    Video {
+        /// The identifier of the video on its hosting platform
+        id: String,
+        /// The preview image of the video
+        preview: Option<ImageRef>,
+    },
+    /// A table
+    Table {
+        /// The number of rows in the table
+        rows_count: u64,
+        /// The number of columns in the table
+        columns_count: u64,
+        /// The non-empty cells of the table
+        cells: Vec<TableCell>,
+    },
+    /// A poll
+    Poll {
+        /// The unique identifier of the poll
+        id: u64,
+        /// The title of the poll, if any
+        title: Option<String>,
+        /// The options that can be voted for
+        options: Vec<String>,
+        /// The minimum level required to vote
+        min_level: f64,
+        /// The minimum karma (over the last 30 days) required to vote
+        min_karma: f64,
+        /// The minimum number of days since registration required to vote
+        min_days: u64,
+    },
+    /// A downloadable file
+    Download {
+        /// The unique identifier of the file resource
+        resource_id: u64,
+        /// The displayed title of the file
+        title: String,
+        /// The size of the file in bytes
+        size: u64,
+    },
+    /// A block of code
+    Code {
+        /// The code itself
+        code: String,
+        /// The language used for syntax highlighting, if specified
+        language: Option<String>,
+    },
+    /// A link to an object within Bonfire (e.g., an account, a fandom, or a publication)
+    CampfireObject {
+        /// The internal link to the object
+        link: String,
+    },
+    /// An unknown item type
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Unknown(i64),
+}
+
+/// Represents a non-empty cell of a [`PostItem::Table`][Item::Table].
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct TableCell {
+    /// The zero-based index of the row the cell is in
+    pub row: u64,
+    /// The zero-based index of the column the cell is in
+    pub column: u64,
+    /// The text of the cell, if any
+    pub text: Option<String>,
+    /// The image of the cell, if any
+    pub image: Option<ImageRef>,
+}
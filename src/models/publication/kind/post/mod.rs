@@ -1,9 +1,13 @@
+mod item;
+
+pub use item::{Item, TableCell};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::client::Request as _;
-use crate::models::publication::{Kind, PostTag, Publishable};
+use crate::models::publication::{CommentBuilder, Kind, PostTag, Publishable};
 use crate::models::{Account, Category, Comment, Fandom, Publication};
+use crate::requests::publication::comment::CreateCommentRequest;
 use crate::requests::publication::post::GetPostRequest;
 use crate::sealed::Sealed;
 use crate::{Client, Result};
@@ -18,8 +22,8 @@ pub struct Post {
     pub author: Account,
     /// The specific category of the fandom in which this publication was posted
     pub category: Category,
-    // /// The content of this post
-    // pub items: Vec<Item>,
+    /// The content of this post
+    pub items: Vec<Item>,
     /// A comment which earned the highest amount of karma
     pub best_comment: Option<Publication<Comment>>,
     /// The total karma received by this publication (can be positive or negative)
@@ -81,4 +85,37 @@ impl Publication<Post> {
             .await?
             .try_into()
     }
+
+    /// Posts a comment composed with a [`CommentBuilder`] under this post.
+    ///
+    /// This operation requires an authenticated client. The comment is validated locally before
+    /// being posted: its text length must be within [`Comment::TEXT_LENGTH_RANGE`] (the text may be
+    /// empty if the comment has an attachment), and its attachments must respect the limits
+    /// defined on [`CommentContent`][crate::models::publication::CommentContent].
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`CreateCommentError::InvalidTextLength`][crate::models::publication::CreateCommentError::InvalidTextLength]
+    ///   if the text is empty and there is no attachment, or if the text is too long.
+    /// * Returns [`CreateCommentError::TooManyImages`][crate::models::publication::CreateCommentError::TooManyImages]
+    ///   if more than [`IMAGES_MAX_COUNT`][crate::models::publication::CommentContent::IMAGES_MAX_COUNT]
+    ///   images are attached.
+    /// * Returns [`CreateCommentError::ImageTooLarge`][crate::models::publication::CreateCommentError::ImageTooLarge]
+    ///   if a static image (including the first frame of a GIF) is too large.
+    /// * Returns [`CreateCommentError::GifTooLarge`][crate::models::publication::CreateCommentError::GifTooLarge]
+    ///   if the GIF is too large.
+    /// * Returns [`CreateCommentError::Blocked`][crate::models::publication::CreateCommentError::Blocked]
+    ///   if the author of the post has blocked the authenticated user.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn comment(
+        &self,
+        client: &Client,
+        comment: &CommentBuilder<'_>,
+    ) -> Result<Publication<Comment>> {
+        comment.validate()?;
+        CreateCommentRequest::new(self.id, comment)
+            .send_request(client)
+            .await?
+            .try_into()
+    }
 }
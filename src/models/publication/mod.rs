@@ -1,12 +1,16 @@
+mod error;
 mod filter;
 mod kind;
+mod rate;
 mod reaction;
 mod status;
 
 use chrono::{DateTime, Utc};
+pub use error::*;
 pub use filter::{AccountFilter, FandomFilter};
 pub use kind::*;
-pub use reaction::Reaction;
+pub use rate::{Rate, RateEntry};
+pub use reaction::{Reaction, ReactionCount};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use status::Status;
@@ -37,4 +41,6 @@ pub struct Publication<T: Publishable = AnyPublication> {
     pub status: Option<Status>,
     /// A value indicating the "hotness" or popularity of this publication
     pub hotness: f32,
+    /// The reactions placed on this publication
+    pub reactions: Vec<Reaction>,
 }
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use futures::Stream;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request as _;
+use crate::models::publication::Publishable;
+use crate::models::streams::auto_paginated_stream;
+use crate::models::{Account, Publication};
+use crate::requests::publication::rate::{
+    CancelRateRequest, ListRatesRequest, RatePublicationRequest,
+};
+use crate::{Client, Result};
+
+/// Represents the direction of a karma rate placed on a publication.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Rate {
+    /// A positive rate (upvote)
+    #[default]
+    Up,
+    /// A negative rate (downvote)
+    Down,
+}
+
+/// Represents a single karma rate placed on a publication.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct RateEntry {
+    /// The unique identifier of the rated publication
+    pub publication_id: u64,
+    /// The account that placed this rate, or `None` if the rate is anonymous and its author is
+    /// hidden from you
+    pub rater: Option<Account>,
+    /// The amount of karma this rate has brought (negative for downvotes)
+    pub karma: f64,
+    /// The date and time when this rate was placed
+    pub rated_at: DateTime<Utc>,
+    /// Indicates if this rate was placed anonymously
+    pub is_anonymous: bool,
+}
+
+impl<T: Publishable> Publication<T> {
+    /// Places a karma rate on this publication, returning the amount of karma it has brought.
+    ///
+    /// This operation requires an authenticated client. The amount of karma depends on the level
+    /// of the authenticated user. If `is_anonymous` is `true`, the rate is placed anonymously, and
+    /// the author of the publication won't see who placed it; pass
+    /// [`Settings::rate_anonymously`][crate::models::Settings::rate_anonymously] to honor the
+    /// user's preference.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`RateError::AlreadyRated`][super::RateError::AlreadyRated] if this publication
+    ///   has already been rated by the authenticated user.
+    /// * Returns [`RateError::OwnPublication`][super::RateError::OwnPublication] if the
+    ///   authenticated user is the author of this publication.
+    /// * Returns [`RateError::RateLimited`][super::RateError::RateLimited] if the authenticated
+    ///   user has placed too many rates recently.
+    /// * Returns [`RateError::KarmaCapReached`][super::RateError::KarmaCapReached] if the
+    ///   authenticated user can't place any more karma on the author of this publication today.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn rate(&self, client: &Client, rate: Rate, is_anonymous: bool) -> Result<f64> {
+        Ok(RatePublicationRequest::new(self.id, rate, is_anonymous)
+            .send_request(client)
+            .await?
+            .into())
+    }
+
+    /// Cancels the karma rate the authenticated user has placed on this publication.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`CancelRateError::NotRated`][super::CancelRateError::NotRated] if this
+    ///   publication hasn't been rated by the authenticated user.
+    /// * Returns [`CancelRateError::TimeExpired`][super::CancelRateError::TimeExpired] if the rate
+    ///   was placed too long ago to be cancelled.
+    /// * Returns [`Error`][crate::Error] if any other error occurs during the request.
+    pub async fn cancel_rate(&self, client: &Client) -> Result<()> {
+        CancelRateRequest::new(self.id).send_request(client).await?;
+        Ok(())
+    }
+
+    /// Retrieves a [`Stream`] of karma rates placed on this publication, from newest to oldest.
+    ///
+    /// This method returns a [`Stream`] that yields individual [`RateEntry`] instances as they are
+    /// retrieved. The stream handles pagination automatically, fetching new pages of results as
+    /// needed. The `offset` parameter can be used to skip a number of rates from the beginning of
+    /// the list. If an [`Error`][crate::Error] occurs during the retrieval of any page, the stream
+    /// will yield that single error and then terminate.
+    pub fn list_rates<'a>(
+        &self,
+        client: &'a Client,
+        offset: usize,
+    ) -> impl Stream<Item = Result<RateEntry>> + 'a {
+        let id = self.id;
+        auto_paginated_stream(
+            move |offset| async move {
+                ListRatesRequest::new(id, offset)
+                    .send_request(client)
+                    .await?
+                    .try_into()
+            },
+            offset,
+            ListRatesRequest::PAGE_SIZE,
+        )
+    }
+}
@@ -1,7 +1,19 @@
+use std::collections::BTreeMap;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// Represents a reaction (emoji) placed on a chat message or comment.
+use crate::client::Request as _;
+use crate::models::Publication;
+use crate::models::publication::Publishable;
+use crate::requests::publication::reaction::{AddReactionRequest, RemoveReactionRequest};
+use crate::{Client, Result};
+
+/// Represents a reaction (emoji) placed on a publication.
+///
+/// Reactions are only identified by their index. Mapping an index to the emoji or image it's
+/// rendered with isn't supported yet, as the app's reaction resources aren't available to this
+/// crate.
 #[derive(Default, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Reaction {
@@ -10,3 +22,83 @@ pub struct Reaction {
     /// The unique identifier of the account that placed this reaction
     pub from_account_id: u64,
 }
+
+/// Represents the reactions of a single index placed on a publication, as returned by
+/// [`Publication::reaction_summary()`].
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ReactionCount {
+    /// The index of the reaction
+    pub index: i64,
+    /// The number of accounts that placed this reaction
+    pub count: u64,
+    /// Indicates if the given account is one of those that placed this reaction
+    pub has_reacted: bool,
+}
+
+impl<T: Publishable> Publication<T> {
+    /// Places a reaction with the given index on this publication.
+    ///
+    /// This operation requires an authenticated client. Reactions can be placed on posts,
+    /// comments and chat messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if an error occurs while sending the request.
+    pub async fn react(&self, client: &Client, index: i64) -> Result<()> {
+        AddReactionRequest::new(self.id, index)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+
+    /// Removes the reaction with the given index that the authenticated user has placed on this
+    /// publication.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`][crate::Error] if an error occurs while sending the request.
+    pub async fn unreact(&self, client: &Client, index: i64) -> Result<()> {
+        RemoveReactionRequest::new(self.id, index)
+            .send_request(client)
+            .await?;
+        Ok(())
+    }
+
+    /// Groups the [`reactions`][Publication::reactions] of this publication by their index,
+    /// counting them and checking whether the account with the given ID is among their authors.
+    ///
+    /// The returned counts are sorted by index.
+    #[must_use]
+    pub fn reaction_summary(&self, account_id: u64) -> Vec<ReactionCount> {
+        let mut counts = BTreeMap::<i64, ReactionCount>::new();
+        for reaction in &self.reactions {
+            let count = counts
+                .entry(reaction.index)
+                .or_insert_with(|| ReactionCount {
+                    index: reaction.index,
+                    ..Default::default()
+                });
+            count.count += 1;
+            count.has_reacted |= reaction.from_account_id == account_id;
+        }
+
+        counts.into_values().collect()
+    }
+
+    /// Groups the [`reactions`][Publication::reactions] of this publication like
+    /// [`Publication::reaction_summary()`], checking whether the authenticated user is among their
+    /// authors.
+    ///
+    /// The user is identified by the client's access token, so no request is sent unless the
+    /// token has to be refreshed.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Unauthenticated`][crate::Error::Unauthenticated] if the client is not
+    ///   authenticated.
+    /// * Returns [`Error`][crate::Error] if an error occurs while refreshing the access token.
+    pub async fn my_reaction_summary(&self, client: &Client) -> Result<Vec<ReactionCount>> {
+        Ok(self.reaction_summary(client.account_id().await?))
+    }
+}
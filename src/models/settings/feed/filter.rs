@@ -0,0 +1,40 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::models::{AnyPublication, Publication, Settings};
+
+/// Represents the feed filters that are applied on the client side, as the server doesn't know
+/// about them.
+///
+/// Only posts are affected by these filters, every other kind of publication is always shown.
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Filter {
+    /// Whether to hide posts marked as NSFW
+    pub hide_nsfw_posts: bool,
+    /// The IDs of the fandoms whose posts are hidden
+    pub blocked_fandom_ids: Vec<u64>,
+}
+impl Filter {
+    /// Creates a new `FeedFilter` from the user's settings and the list of blocked fandoms, which
+    /// can be retrieved with
+    /// [`Account::list_blocked_fandom_ids()`][crate::models::Account::list_blocked_fandom_ids].
+    #[must_use]
+    pub fn new(settings: &Settings, blocked_fandom_ids: Vec<u64>) -> Self {
+        Self {
+            hide_nsfw_posts: !settings.show_nsfw_posts,
+            blocked_fandom_ids,
+        }
+    }
+
+    /// Checks whether the given publication passes this filter.
+    #[must_use]
+    pub fn is_shown(&self, publication: &Publication<AnyPublication>) -> bool {
+        let AnyPublication::Post(post) = &publication.kind else {
+            return true;
+        };
+
+        (!self.hide_nsfw_posts || !post.is_nsfw)
+            && !self.blocked_fandom_ids.contains(&post.fandom.id)
+    }
+}
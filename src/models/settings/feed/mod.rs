@@ -1,10 +1,18 @@
+mod filter;
 mod kind;
 
+use chrono::{DateTime, TimeDelta, Utc};
+pub use filter::Filter;
+use futures::{Stream, StreamExt as _, future};
 pub use kind::Kind;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::models::{Category, Language};
+use crate::client::Request as _;
+use crate::models::streams::paginated_stream;
+use crate::models::{Category, Language, Publication};
+use crate::requests::publication::ListFeedRequest;
+use crate::{Client, Result};
 
 /// Represents the user's feed settings.
 #[derive(Default, Clone, Debug)]
@@ -22,3 +30,114 @@ pub struct Feed {
     /// The kinds of posts to show in the feed, or empty if no feed kind has been selected yet
     pub kinds: Vec<Kind>,
 }
+impl Feed {
+    /// Retrieves a [`Stream`] of publications in the feed of the given kind, from newest to
+    /// oldest.
+    ///
+    /// [`FeedKind::AllWithFollows`][Kind::AllWithFollows] contains posts from all accounts,
+    /// including followed ones, while [`FeedKind::Follows`][Kind::Follows] only contains posts
+    /// from followed accounts. The languages, categories, important and closed posts filters of
+    /// these settings are applied by the server, so they don't have to be saved beforehand. If
+    /// `filter` is provided, the publications it rejects are skipped without affecting
+    /// pagination.
+    ///
+    /// This method returns a [`Stream`] that yields individual publications as they are
+    /// retrieved. The stream handles pagination automatically, fetching new pages of results as
+    /// needed. The `offset_date` parameter can be used to skip publications created after a
+    /// specific time. If an [`Error`][crate::Error] occurs during the retrieval of any page, the
+    /// stream will yield that single error and then terminate.
+    pub fn stream<'a>(
+        &'a self,
+        client: &'a Client,
+        kind: Kind,
+        offset_date: Option<DateTime<Utc>>,
+        filter: Option<&'a Filter>,
+    ) -> impl Stream<Item = Result<Publication>> + 'a {
+        paginated_stream(
+            move |cursor: Cursor| {
+                let kind = kind.clone();
+
+                async move {
+                    let publications: Vec<Publication> =
+                        ListFeedRequest::new(self, kind, cursor.offset_date)
+                            .send_request(client)
+                            .await?
+                            .try_into()?;
+
+                    // Publications that have already been yielded are replaced with `None`, so
+                    // the length of the page is preserved
+                    Ok(publications
+                        .into_iter()
+                        .map(|publication| {
+                            (!cursor.seen_ids.contains(&publication.id)).then_some(publication)
+                        })
+                        .collect())
+                }
+            },
+            Cursor {
+                offset_date,
+                seen_ids: Vec::new(),
+            },
+            |publications: &Vec<Option<Publication>>, cursor| {
+                (publications.len() >= ListFeedRequest::PAGE_SIZE)
+                    .then(|| cursor.next(publications))
+            },
+        )
+        .filter_map(move |result| {
+            future::ready(match result {
+                Ok(Some(publication))
+                    if filter.is_none_or(|filter| filter.is_shown(&publication)) =>
+                {
+                    Some(Ok(publication))
+                }
+                Ok(_) => None,
+                Err(error) => Some(Err(error)),
+            })
+        })
+    }
+}
+
+// The server only returns publications created before the offset date. Paging by the date of the
+// last publication would skip the rest of the publications created at the same millisecond, so
+// the next page starts a millisecond later and the publications of the previous page created at
+// that date are skipped instead
+#[derive(Clone)]
+struct Cursor {
+    offset_date: Option<DateTime<Utc>>,
+    seen_ids: Vec<u64>,
+}
+impl Cursor {
+    fn next(&self, publications: &[Option<Publication>]) -> Self {
+        // Publications are sorted from newest to oldest, so only the ones created at the offset
+        // date can have been seen. If the last one has been seen, every publication of the page
+        // has been created at that date, and the rest of them can only be skipped
+        let Some(Some(last)) = publications.last() else {
+            return Self {
+                offset_date: self
+                    .offset_date
+                    .map(|date| date - TimeDelta::milliseconds(1)),
+                seen_ids: Vec::new(),
+            };
+        };
+
+        let date = last.created_at;
+        let offset_date = date + TimeDelta::milliseconds(1);
+        let mut seen_ids = if self.offset_date == Some(offset_date) {
+            self.seen_ids.clone()
+        } else {
+            Vec::new()
+        };
+        seen_ids.extend(
+            publications
+                .iter()
+                .flatten()
+                .filter(|publication| publication.created_at == date)
+                .map(|publication| publication.id),
+        );
+
+        Self {
+            offset_date: Some(offset_date),
+            seen_ids,
+        }
+    }
+}
@@ -5,7 +5,7 @@ mod style;
 
 use std::collections::HashMap;
 
-pub use feed::{Feed, Kind as FeedKind};
+pub use feed::{Feed, Filter as FeedFilter, Kind as FeedKind};
 pub use notifications::Notifications;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -3,7 +3,7 @@ use futures::{Stream, StreamExt as _, stream};
 
 use crate::Result;
 
-pub(super) fn paginated_stream<'a, O, T, R, N>(
+pub(crate) fn paginated_stream<'a, O, T, R, N>(
     request_fn: R,
     offset: O,
     next_offset_fn: N,
@@ -41,7 +41,7 @@ where
     .flatten()
 }
 
-pub(super) fn auto_paginated_stream<'a, T, R>(
+pub(crate) fn auto_paginated_stream<'a, T, R>(
     request_fn: R,
     offset: usize,
     page_size: usize,
@@ -1,6 +1,6 @@
-#[cfg(feature = "fcm")]
-pub use crate::client::FcmError;
 pub use crate::client::JwtError;
+#[cfg(feature = "fcm")]
+pub use crate::client::{FcmError, PushListener};
 pub use crate::models::*;
 pub use crate::{
     Client, ClientBuilder, Error as ApiError, MeliorError, Result as ApiResult, RootError,
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::auth::{LinkedLogins, Session};
+use crate::queries::raw::auth::{RawSecuritySettings, RawSession};
+use crate::{Client, MeliorError, Result};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Me {
+    security_settings: RawSecuritySettings,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Response {
+    me: Me,
+    active_sessions: Vec<RawSession>,
+}
+
+impl From<Response> for Vec<Session> {
+    fn from(value: Response) -> Self {
+        value.active_sessions.into_iter().map(Into::into).collect()
+    }
+}
+
+impl From<Response> for LinkedLogins {
+    fn from(value: Response) -> Self {
+        value.me.security_settings.into()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct AccountSecurityQuery {}
+impl AccountSecurityQuery {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Request for AccountSecurityQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query(
+                "AccountSecurityQuery",
+                "auth/AccountSecurityQuery.graphql",
+                self,
+            )
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::ChangeEmailError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChangeEmailQuery<'a> {
+    new_email: &'a str,
+}
+impl<'a> ChangeEmailQuery<'a> {
+    pub(crate) fn new(new_email: &'a str) -> Self {
+        Self { new_email }
+    }
+}
+
+impl Request for ChangeEmailQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = ChangeEmailError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query(
+                "ChangeEmailMutation",
+                "auth/ChangeEmailMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::ChangePasswordError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChangePasswordQuery<'a> {
+    old_password: &'a str,
+    new_password: &'a str,
+}
+impl<'a> ChangePasswordQuery<'a> {
+    pub(crate) fn new(old_password: &'a str, new_password: &'a str) -> Self {
+        Self {
+            old_password,
+            new_password,
+        }
+    }
+}
+
+impl Request for ChangePasswordQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = ChangePasswordError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query(
+                "ChangePasswordMutation",
+                "auth/ChangePasswordMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
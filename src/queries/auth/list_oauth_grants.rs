@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::auth::OAuthGrant;
+use crate::queries::raw::auth::RawOAuthGrant;
+use crate::{Client, MeliorError, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "oauth2Grants")]
+    grants: Vec<RawOAuthGrant>,
+}
+
+impl From<Response> for Vec<OAuthGrant> {
+    fn from(value: Response) -> Self {
+        value.grants.into_iter().map(Into::into).collect()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct ListOAuthGrantsQuery {
+    limit: usize,
+    offset: usize,
+}
+impl ListOAuthGrantsQuery {
+    pub(crate) const PAGE_SIZE: usize = 20;
+
+    pub(crate) fn new(offset: usize) -> Self {
+        Self {
+            limit: Self::PAGE_SIZE,
+            offset,
+        }
+    }
+}
+
+impl Request for ListOAuthGrantsQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query("OAuthGrants", "auth/OAuthGrants.graphql", self)
+            .await
+    }
+}
@@ -1,9 +1,27 @@
+mod account_security;
+mod change_email;
+mod change_password;
 mod get_profile;
+mod list_oauth_grants;
 mod login_email;
 mod logout;
 mod refresh;
+mod register_email;
+mod resend_verification;
+mod revoke_oauth_grant;
+mod send_password_recovery;
+mod terminate_session;
 
+pub(crate) use account_security::AccountSecurityQuery;
+pub(crate) use change_email::ChangeEmailQuery;
+pub(crate) use change_password::ChangePasswordQuery;
 pub(crate) use get_profile::GetProfileQuery;
+pub(crate) use list_oauth_grants::ListOAuthGrantsQuery;
 pub(crate) use login_email::LoginEmailQuery;
 pub(crate) use logout::LogoutQuery;
 pub(crate) use refresh::RefreshQuery;
+pub(crate) use register_email::RegisterEmailQuery;
+pub(crate) use resend_verification::ResendVerificationQuery;
+pub(crate) use revoke_oauth_grant::RevokeOAuthGrantQuery;
+pub(crate) use send_password_recovery::SendPasswordRecoveryQuery;
+pub(crate) use terminate_session::TerminateSessionQuery;
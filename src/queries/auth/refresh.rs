@@ -33,6 +33,8 @@ impl Request for RefreshQuery<'_> {
     type Response = Response;
     type Error = RefreshError;
 
+    const IS_RETRYABLE: bool = false;
+
     async fn send_request(&self, client: &Client) -> Result<Response> {
         client
             .send_query_authless(
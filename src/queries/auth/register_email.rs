@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request;
+use crate::models::Auth;
+use crate::models::auth::RegisterError;
+use crate::queries::raw::RawAuth;
+use crate::{Client, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "registerEmail")]
+    auth: RawAuth,
+}
+
+impl From<Response> for Auth {
+    fn from(value: Response) -> Self {
+        value.auth.into()
+    }
+}
+
+#[derive(Serialize)]
+struct RegisterInput<'a> {
+    email: &'a str,
+    password: &'a str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct RegisterEmailQuery<'a> {
+    input: RegisterInput<'a>,
+}
+impl<'a> RegisterEmailQuery<'a> {
+    pub(crate) fn new(email: &'a str, password: &'a str) -> Self {
+        Self {
+            input: RegisterInput { email, password },
+        }
+    }
+}
+
+impl Request for RegisterEmailQuery<'_> {
+    type Response = Response;
+    type Error = RegisterError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query_authless(
+                "RegisterEmailMutation",
+                "auth/RegisterEmailMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
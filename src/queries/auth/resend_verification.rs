@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::ResendVerificationError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+pub(crate) struct ResendVerificationQuery<'a> {
+    email: &'a str,
+}
+impl<'a> ResendVerificationQuery<'a> {
+    pub(crate) fn new(email: &'a str) -> Self {
+        Self { email }
+    }
+}
+
+impl Request for ResendVerificationQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = ResendVerificationError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query_authless(
+                "ResendVerificationMutation",
+                "auth/ResendVerificationMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::RevokeOAuthGrantError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RevokeOAuthGrantQuery<'a> {
+    grant_id: &'a str,
+}
+impl<'a> RevokeOAuthGrantQuery<'a> {
+    pub(crate) fn new(grant_id: &'a str) -> Self {
+        Self { grant_id }
+    }
+}
+
+impl Request for RevokeOAuthGrantQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = RevokeOAuthGrantError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query("RevokeOAuthGrant", "auth/RevokeOAuthGrant.graphql", self)
+            .await
+    }
+}
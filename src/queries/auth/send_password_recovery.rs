@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::PasswordRecoveryError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+pub(crate) struct SendPasswordRecoveryQuery<'a> {
+    email: &'a str,
+}
+impl<'a> SendPasswordRecoveryQuery<'a> {
+    pub(crate) fn new(email: &'a str) -> Self {
+        Self { email }
+    }
+}
+
+impl Request for SendPasswordRecoveryQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = PasswordRecoveryError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query_authless(
+                "SendPasswordRecoveryMutation",
+                "auth/SendPasswordRecoveryMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::auth::TerminateSessionError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TerminateSessionQuery<'a> {
+    session_id: &'a str,
+}
+impl<'a> TerminateSessionQuery<'a> {
+    pub(crate) fn new(session_id: &'a str) -> Self {
+        Self { session_id }
+    }
+}
+
+impl Request for TerminateSessionQuery<'_> {
+    type Response = EmptyResponse;
+    type Error = TerminateSessionError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_query(
+                "TerminateSessionMutation",
+                "auth/TerminateSessionMutation.graphql",
+                self,
+            )
+            .await
+    }
+}
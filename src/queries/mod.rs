@@ -1,5 +1,6 @@
 pub(crate) mod auth;
 mod error;
+pub(crate) mod other;
 pub(crate) mod profile;
 mod raw;
 
@@ -11,9 +12,9 @@ pub(crate) use crate::queries::raw::RawMeliorError;
 
 #[derive(Serialize)]
 pub(crate) struct MeliorQuery<'a, R: Request> {
-    pub operation_name: &'static str,
+    pub operation_name: &'a str,
     pub variables: &'a R,
-    pub query: &'static str,
+    pub query: &'a str,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{InfallibleRequest, Request};
+use crate::{Client, MeliorError, Result};
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub(crate) struct GraphqlQuery<'a> {
+    #[serde(skip)]
+    operation_name: &'a str,
+    #[serde(skip)]
+    query: &'a str,
+    variables: Value,
+}
+impl<'a> GraphqlQuery<'a> {
+    pub(crate) fn new(operation_name: &'a str, query: &'a str, variables: Value) -> Self {
+        Self {
+            operation_name,
+            query,
+            variables,
+        }
+    }
+}
+
+impl Request for GraphqlQuery<'_> {
+    type Response = Value;
+    type Error = InfallibleRequest<MeliorError>;
+
+    // The operation may be a mutation, so it may not be safe to send it twice
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Value> {
+        client
+            .send_graphql(self.operation_name, self.query, self)
+            .await
+    }
+}
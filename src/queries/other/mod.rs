@@ -0,0 +1,3 @@
+mod graphql;
+
+pub(crate) use graphql::GraphqlQuery;
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::account::BadgeDetails;
+use crate::queries::raw::RawBadgeDetails;
+use crate::{Client, Error, MeliorError, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    badge: RawBadgeDetails,
+}
+
+impl TryFrom<Response> for BadgeDetails {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.badge.try_into()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct BadgeDetailsQuery {
+    id: String,
+}
+impl BadgeDetailsQuery {
+    pub(crate) fn new(id: u64) -> Self {
+        Self { id: id.to_string() }
+    }
+}
+
+impl Request for BadgeDetailsQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query("BadgeFlyoutQuery", "profile/BadgeFlyout.graphql", self)
+            .await
+    }
+}
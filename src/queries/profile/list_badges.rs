@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::Badge;
+use crate::queries::raw::RawBadge;
+use crate::{Client, Error, MeliorError, Result};
+
+#[derive(Deserialize)]
+struct Edge {
+    cursor: String,
+    node: RawBadge,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection {
+    edges: Vec<Edge>,
+    page_info: PageInfo,
+}
+
+#[derive(Deserialize)]
+struct User {
+    badges: Connection,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "userById")]
+    user: User,
+}
+
+// Each badge is paired with the cursor of the next page, which is only present on the last badge
+// of a page if there are more pages to load
+impl TryFrom<Response> for Vec<(Badge, Option<String>)> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        let connection = value.user.badges;
+        let length = connection.edges.len();
+
+        connection
+            .edges
+            .into_iter()
+            .enumerate()
+            .map(|(index, edge)| {
+                let next_cursor = (index + 1 == length && connection.page_info.has_next_page)
+                    .then_some(edge.cursor);
+                Ok((edge.node.try_into()?, next_cursor))
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ListBadgesQuery {
+    user_id: String,
+    after: Option<String>,
+}
+impl ListBadgesQuery {
+    pub(crate) fn new(user_id: u64, after: Option<String>) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            after,
+        }
+    }
+}
+
+impl Request for ListBadgesQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query("BadgeList", "profile/BadgeList.graphql", self)
+            .await
+    }
+}
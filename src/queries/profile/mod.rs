@@ -1,3 +1,11 @@
+mod badge_details;
+mod list_badges;
+mod set_badge_shelf;
+mod set_badge_shelf_visible;
 mod set_birthday;
 
+pub(crate) use badge_details::BadgeDetailsQuery;
+pub(crate) use list_badges::ListBadgesQuery;
+pub(crate) use set_badge_shelf::SetBadgeShelfQuery;
+pub(crate) use set_badge_shelf_visible::SetBadgeShelfVisibleQuery;
 pub(crate) use set_birthday::SetBirthdayQuery;
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::Badge;
+use crate::queries::raw::RawBadge;
+use crate::{Client, Error, MeliorError, Result};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Shelf {
+    badge_shelf: Option<Vec<RawBadge>>,
+}
+
+impl TryFrom<Shelf> for Vec<Badge> {
+    type Error = Error;
+
+    fn try_from(value: Shelf) -> Result<Self> {
+        value
+            .badge_shelf
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "setBadgeShelf")]
+    shelf: Shelf,
+}
+
+impl TryFrom<Response> for Vec<Badge> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.shelf.try_into()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct SetBadgeShelfQuery {
+    badges: Vec<String>,
+}
+impl SetBadgeShelfQuery {
+    pub(crate) fn new(badge_ids: &[u64]) -> Self {
+        Self {
+            badges: badge_ids.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl Request for SetBadgeShelfQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    // The whole shelf is replaced at once, so sending the same request again is harmless
+    const IS_RETRYABLE: bool = true;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query("SetBadgeShelf", "profile/SetBadgeShelf.graphql", self)
+            .await
+    }
+}
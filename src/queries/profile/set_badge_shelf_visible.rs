@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::Badge;
+use crate::queries::profile::set_badge_shelf::Shelf;
+use crate::{Client, Error, MeliorError, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "showBadgeShelf")]
+    shelf: Shelf,
+}
+
+impl TryFrom<Response> for Vec<Badge> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.shelf.try_into()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct SetBadgeShelfVisibleQuery {
+    visible: bool,
+}
+impl SetBadgeShelfVisibleQuery {
+    pub(crate) fn new(visible: bool) -> Self {
+        Self { visible }
+    }
+}
+
+impl Request for SetBadgeShelfVisibleQuery {
+    type Response = Response;
+    type Error = InfallibleRequest<MeliorError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_query(
+                "SetBadgeShelfVisible",
+                "profile/SetBadgeShelfVisible.graphql",
+                self,
+            )
+            .await
+    }
+}
@@ -1,7 +1,11 @@
 mod error;
+mod oauth;
+mod session;
 
 pub(crate) use error::*;
+pub(crate) use oauth::RawOAuthGrant;
 use serde::Deserialize;
+pub(crate) use session::{RawSecuritySettings, RawSession};
 
 use crate::models::Auth;
 
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::models::auth::{OAuthClient, OAuthGrant};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawOAuthClient {
+    pub id: String,
+    pub display_name: String,
+    pub official: bool,
+    pub tos_url: Option<String>,
+    pub privacy_policy_url: Option<String>,
+}
+
+impl From<RawOAuthClient> for OAuthClient {
+    fn from(value: RawOAuthClient) -> Self {
+        Self {
+            id: value.id,
+            name: value.display_name,
+            is_official: value.official,
+            tos_url: value.tos_url,
+            privacy_policy_url: value.privacy_policy_url,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawOAuthGrant {
+    pub id: String,
+    pub client: RawOAuthClient,
+    pub scope: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<RawOAuthGrant> for OAuthGrant {
+    fn from(value: RawOAuthGrant) -> Self {
+        Self {
+            id: value.id,
+            client: value.client.into(),
+            scope: value.scope,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
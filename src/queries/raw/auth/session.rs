@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::models::auth::{LinkedLogins, Session};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawSession {
+    pub id: String,
+    pub active: bool,
+    pub last_active: DateTime<Utc>,
+    pub user_agent: String,
+    pub ip: String,
+    pub current: bool,
+}
+
+impl From<RawSession> for Session {
+    fn from(value: RawSession) -> Self {
+        Self {
+            id: value.id,
+            is_active: value.active,
+            last_active_at: value.last_active,
+            user_agent: value.user_agent,
+            ip: value.ip,
+            is_current: value.current,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawSecuritySettings {
+    pub google_linked: bool,
+    pub firebase_linked: bool,
+}
+
+impl From<RawSecuritySettings> for LinkedLogins {
+    fn from(value: RawSecuritySettings) -> Self {
+        Self {
+            is_google_linked: value.google_linked,
+            is_firebase_linked: value.firebase_linked,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use crate::models::ImageRef;
+use crate::queries::raw::parse_id;
+use crate::{Error, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct RawImage {
+    pub id: String,
+    pub url: String,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl TryFrom<RawImage> for ImageRef {
+    type Error = Error;
+
+    fn try_from(value: RawImage) -> Result<Self> {
+        Ok(Self {
+            id: parse_id(&value.id)?,
+            uri: match value.url.as_str() {
+                "" => None,
+                _ => Some(value.url),
+            },
+            width: value.width,
+            height: value.height,
+        })
+    }
+}
@@ -1,8 +1,18 @@
 pub(super) mod auth;
 pub(super) mod error;
+mod image;
 pub(super) mod profile;
 
 pub(super) use auth::RawAuth;
 pub(crate) use error::RawMeliorError;
 pub(super) use error::{RawQueryLocation, RawQueryPath};
-pub(super) use profile::RawProfile;
+pub(super) use image::RawImage;
+pub(super) use profile::{RawBadge, RawBadgeDetails, RawProfile};
+
+use crate::{Error, Result};
+
+// IDs are represented as strings in GraphQL, but they always contain an integer
+fn parse_id(id: &str) -> Result<u64> {
+    id.parse()
+        .map_err(|error| Error::ConversionError(format!("failed to convert id into u64 ({error})")))
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::models::Badge;
+use crate::models::account::BadgeDetails;
+use crate::queries::raw::{RawImage, parse_id};
+use crate::{Error, Result};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawBadge {
+    pub id: String,
+    pub image: RawImage,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<RawBadge> for Badge {
+    type Error = Error;
+
+    fn try_from(value: RawBadge) -> Result<Self> {
+        let id = parse_id(&value.id)?;
+        #[expect(deprecated)]
+        Ok(Self {
+            id,
+            index: i64::try_from(id).map_err(|error| {
+                Error::ConversionError(format!("failed to convert badge id into i64 ({error})"))
+            })?,
+            name: value.name,
+            description: value.description.unwrap_or_default(),
+            created_at: value.created_at,
+            image: value.image.try_into()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawBadgeOwner {
+    pub id: String,
+    pub username: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawBadgeDetails {
+    #[serde(flatten)]
+    pub badge: RawBadge,
+    pub fandom_id: Option<String>,
+    pub link: Option<String>,
+    pub user: RawBadgeOwner,
+}
+
+impl TryFrom<RawBadgeDetails> for BadgeDetails {
+    type Error = Error;
+
+    fn try_from(value: RawBadgeDetails) -> Result<Self> {
+        Ok(Self {
+            badge: value.badge.try_into()?,
+            fandom_id: value.fandom_id.as_deref().map(parse_id).transpose()?,
+            link: value.link,
+            owner_id: parse_id(&value.user.id)?,
+            owner_name: value.user.username,
+        })
+    }
+}
@@ -1,7 +1,12 @@
+mod badge;
+
 use chrono::NaiveDate;
 use serde::Deserialize;
 
+pub(crate) use badge::{RawBadge, RawBadgeDetails};
+
 use crate::models::Profile;
+use crate::queries::raw::parse_id;
 use crate::{Error, Result};
 
 #[derive(Deserialize)]
@@ -21,10 +26,7 @@ impl TryFrom<RawProfile> for Profile {
 
     fn try_from(value: RawProfile) -> Result<Self> {
         Ok(Self {
-            // This field will always contain an integer, trust me
-            id: value.id.parse().map_err(|error| {
-                Error::ConversionError(format!("failed to convert id into u64 ({error})"))
-            })?,
+            id: parse_id(&value.id)?,
             name: value.name,
             email: value.email,
             cached_level: value.cached_level / 100.0,
@@ -4,6 +4,7 @@ mod get_info;
 mod get_stat;
 mod list_banned;
 mod list_online;
+mod list_rates;
 pub(crate) mod profile;
 mod report;
 mod search_accounts;
@@ -14,6 +15,7 @@ pub(crate) use get_info::GetInfoRequest;
 pub(crate) use get_stat::GetStatRequest;
 pub(crate) use list_banned::ListBannedRequest;
 pub(crate) use list_online::ListOnlineRequest;
+pub(crate) use list_rates::ListAccountRatesRequest;
 pub(crate) use report::ReportRequest;
 pub(crate) use search_accounts::SearchAccountsRequest;
 pub(crate) use set_referrer::SetReferrerRequest;
@@ -20,6 +20,8 @@ impl Request for ReportRequest<'_> {
     type Response = EmptyResponse;
     type Error = ReportError;
 
+    const IS_RETRYABLE: bool = false;
+
     async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
         client
             .send_request("RAccountsReport", self, Vec::new())
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::chat::MessageAnchor;
+use crate::models::{ChatMessage, ChatTag, Publication};
+use crate::requests::raw::{RawChatMessage, RawChatTag, RawPublication};
+use crate::{Client, Error, Result, RootError};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "units")]
+    messages: Vec<RawPublication<RawChatMessage>>,
+    #[serde(skip)]
+    is_backwards: bool,
+}
+
+impl TryFrom<Response> for Vec<Publication<ChatMessage>> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        // The server always returns a page sorted by the creation date in ascending order. When
+        // walking backwards, the page is reversed so that the last element is the oldest one and
+        // can be used as the cursor for the next page
+        let messages = value.messages.into_iter().map(TryInto::try_into);
+
+        if value.is_backwards {
+            messages.rev().collect()
+        } else {
+            messages.collect()
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ListMessagesRequest {
+    tag: RawChatTag,
+    offset_date: i64,
+    offset_message_id: u64,
+    #[serde(rename = "old")]
+    is_backwards: bool,
+}
+impl ListMessagesRequest {
+    pub(crate) const PAGE_SIZE: usize = 50;
+
+    pub(crate) fn new(
+        tag: ChatTag,
+        anchor: &MessageAnchor,
+        offset_date: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            tag: tag.into(),
+            offset_date: offset_date.map_or(0, |date| date.timestamp_millis()),
+            offset_message_id: anchor.message_id(),
+            is_backwards: anchor.is_backwards(),
+        }
+    }
+}
+
+impl Request for ListMessagesRequest {
+    type Response = Response;
+    type Error = InfallibleRequest<RootError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        let mut response = client
+            .send_request("RChatMessageGetAll", self, Vec::new())
+            .await?;
+
+        response.is_backwards = self.is_backwards;
+        Ok(response)
+    }
+}
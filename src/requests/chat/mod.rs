@@ -1,7 +1,11 @@
 mod get_chat;
 mod list_chats;
+mod list_messages;
 mod notify_typing;
+mod send_message;
 
 pub(crate) use get_chat::GetChatRequest;
 pub(crate) use list_chats::ListChatsRequest;
+pub(crate) use list_messages::ListMessagesRequest;
 pub(crate) use notify_typing::NotifyTypingRequest;
+pub(crate) use send_message::SendMessageRequest;
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request;
+use crate::models::chat::{MessageBuilder, SendMessageError};
+use crate::models::common::Attachment;
+use crate::models::{ChatMessage, ChatTag, Publication};
+use crate::requests::raw::{RawChatMessage, RawChatTag, RawPublication};
+use crate::{Client, Error, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    message: RawPublication<RawChatMessage>,
+}
+
+impl TryFrom<Response> for Publication<ChatMessage> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.message.try_into()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SendMessageRequest<'a> {
+    tag: RawChatTag,
+    text: &'a str,
+    #[serde(rename = "quoteId")]
+    reply_to_id: u64,
+    new_formatting: bool,
+    #[serde(skip)]
+    attachment: Attachment<'a>,
+}
+impl<'a> SendMessageRequest<'a> {
+    pub(crate) fn new(tag: ChatTag, message: &MessageBuilder<'a>) -> Self {
+        Self {
+            tag: tag.into(),
+            text: message.text,
+            reply_to_id: message.reply_to.unwrap_or(0),
+            new_formatting: message.has_new_formatting,
+            attachment: message.attachment.clone(),
+        }
+    }
+}
+
+impl Request for SendMessageRequest<'_> {
+    type Response = Response;
+    type Error = SendMessageError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request(
+                "RChatMessageCreate",
+                self,
+                self.attachment.to_request_attachments(),
+            )
+            .await
+    }
+}
@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use crate::client::Request;
 pub(crate) use crate::requests::raw::RawRootError;
+#[cfg(feature = "fcm")]
+pub(crate) use crate::requests::raw::{RawPushEvent, RawPushPayload};
 
 fn serialize_data_output<S: Serializer>(
     value: &[Option<i32>],
@@ -35,7 +37,7 @@ pub(crate) struct RootRequest<'a, R: Request> {
     #[serde(flatten)]
     pub content: &'a R,
     #[serde(rename = "J_REQUEST_NAME")]
-    pub request_name: &'static str,
+    pub request_name: &'a str,
     #[serde(
         serialize_with = "serialize_data_output",
         skip_serializing_if = "Vec::is_empty"
@@ -1,5 +1,7 @@
 mod bootstrap;
+mod root_call;
 mod save_settings;
 
 pub(crate) use bootstrap::BootstrapRequest;
+pub(crate) use root_call::RootCallRequest;
 pub(crate) use save_settings::SaveSettingsRequest;
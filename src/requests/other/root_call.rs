@@ -0,0 +1,42 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::{Client, Result, RootError};
+
+#[derive(Serialize)]
+pub(crate) struct RootCallRequest<'a> {
+    #[serde(skip)]
+    name: &'a str,
+    #[serde(flatten)]
+    params: Map<String, Value>,
+    #[serde(skip)]
+    attachments: Vec<&'a [u8]>,
+}
+impl<'a> RootCallRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        params: Map<String, Value>,
+        attachments: Vec<&'a [u8]>,
+    ) -> Self {
+        Self {
+            name,
+            params,
+            attachments,
+        }
+    }
+}
+
+impl Request for RootCallRequest<'_> {
+    type Response = Value;
+    type Error = InfallibleRequest<RootError>;
+
+    // Nothing is known about the request, so it may not be safe to send it twice
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Value> {
+        client
+            .send_request(self.name, self, self.attachments.clone())
+            .await
+    }
+}
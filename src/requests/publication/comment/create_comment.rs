@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request;
+use crate::models::common::Attachment;
+use crate::models::publication::{CommentBuilder, CreateCommentError};
+use crate::models::{Comment, Publication};
+use crate::requests::raw::{RawComment, RawPublication};
+use crate::{Client, Error, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    comment: RawPublication<RawComment>,
+}
+
+impl TryFrom<Response> for Publication<Comment> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.comment.try_into()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateCommentRequest<'a> {
+    #[serde(rename = "unitId")]
+    parent_id: u64,
+    text: &'a str,
+    #[serde(rename = "quoteId")]
+    reply_to_id: u64,
+    sticker_id: u64,
+    new_formatting: bool,
+    #[serde(skip)]
+    attachment: Attachment<'a>,
+}
+impl<'a> CreateCommentRequest<'a> {
+    pub(crate) fn new(parent_id: u64, comment: &CommentBuilder<'a>) -> Self {
+        Self {
+            parent_id,
+            text: comment.text,
+            reply_to_id: comment.reply_to.unwrap_or(0),
+            sticker_id: comment.sticker_id.unwrap_or(0),
+            new_formatting: comment.has_new_formatting,
+            attachment: comment.attachment.clone(),
+        }
+    }
+}
+
+impl Request for CreateCommentRequest<'_> {
+    type Response = Response;
+    type Error = CreateCommentError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request(
+                "RCommentsCreate",
+                self,
+                self.attachment.to_request_attachments(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request;
+use crate::models::publication::EditCommentError;
+use crate::models::{Comment, Publication};
+use crate::requests::raw::{RawComment, RawPublication};
+use crate::{Client, Error, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    comment: RawPublication<RawComment>,
+}
+
+impl TryFrom<Response> for Publication<Comment> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.comment.try_into()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct EditCommentRequest<'a> {
+    #[serde(rename = "commentId")]
+    id: u64,
+    text: &'a str,
+}
+impl<'a> EditCommentRequest<'a> {
+    pub(crate) fn new(id: u64, text: &'a str) -> Self {
+        Self { id, text }
+    }
+}
+
+impl Request for EditCommentRequest<'_> {
+    type Response = Response;
+    type Error = EditCommentError;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request("RCommentsChange", self, Vec::new())
+            .await
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::publication::Kind;
+use crate::models::{Comment, Publication};
+use crate::requests::raw::publication::RawKind;
+use crate::requests::raw::{RawComment, RawPublication};
+use crate::{Client, Error, Result, RootError};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    units: Vec<RawPublication<RawComment>>,
+}
+
+impl TryFrom<Response> for Vec<Publication<Comment>> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.units.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct ListCommentsRequest {
+    #[serde(rename = "unitId")]
+    parent_id: u64,
+    #[serde(rename = "unitType")]
+    parent_kind: RawKind,
+    offset: usize,
+}
+impl ListCommentsRequest {
+    pub(crate) const PAGE_SIZE: usize = 20;
+
+    pub(crate) fn new(parent_id: u64, parent_kind: Kind, offset: usize) -> Self {
+        Self {
+            parent_id,
+            parent_kind: parent_kind.into(),
+            offset,
+        }
+    }
+}
+
+impl Request for ListCommentsRequest {
+    type Response = Response;
+    type Error = InfallibleRequest<RootError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request("RCommentsGetAll", self, Vec::new())
+            .await
+    }
+}
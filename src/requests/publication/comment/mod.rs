@@ -0,0 +1,9 @@
+mod create_comment;
+mod edit_comment;
+mod list_comments;
+mod remove_comment;
+
+pub(crate) use create_comment::CreateCommentRequest;
+pub(crate) use edit_comment::EditCommentRequest;
+pub(crate) use list_comments::ListCommentsRequest;
+pub(crate) use remove_comment::RemoveCommentRequest;
@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, InfallibleRequest, Request};
+use crate::{Client, Result, RootError};
+
+#[derive(Serialize)]
+pub(crate) struct RemoveCommentRequest {
+    #[serde(rename = "commentId")]
+    id: u64,
+}
+impl RemoveCommentRequest {
+    pub(crate) fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
+
+impl Request for RemoveCommentRequest {
+    type Response = EmptyResponse;
+    type Error = InfallibleRequest<RootError>;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_request("RCommentsRemove", self, Vec::new())
+            .await
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::Publication;
+use crate::models::settings::{Feed, FeedKind};
+use crate::requests::raw::{RawCategory, RawFeedKind, RawLanguage, RawPublication};
+use crate::{Client, Error, Result, RootError};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    units: Vec<RawPublication>,
+}
+
+impl TryFrom<Response> for Vec<Publication> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.units.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ListFeedRequest {
+    feed_kind: RawFeedKind,
+    offset_date: i64,
+    languages: Vec<RawLanguage>,
+    categories: Vec<RawCategory>,
+    #[serde(rename = "important")]
+    show_important: bool,
+    #[serde(rename = "closed")]
+    show_closed: bool,
+}
+impl ListFeedRequest {
+    pub(crate) const PAGE_SIZE: usize = 20;
+
+    pub(crate) fn new(feed: &Feed, kind: FeedKind, offset_date: Option<DateTime<Utc>>) -> Self {
+        Self {
+            feed_kind: kind.into(),
+            offset_date: offset_date.map_or(0, |date| date.timestamp_millis()),
+            languages: feed.languages.iter().cloned().map(Into::into).collect(),
+            categories: feed.categories.iter().cloned().map(Into::into).collect(),
+            show_important: feed.show_important,
+            show_closed: feed.show_closed,
+        }
+    }
+}
+
+impl Request for ListFeedRequest {
+    type Response = Response;
+    type Error = InfallibleRequest<RootError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request("RPublicationsFeedGetAll", self, Vec::new())
+            .await
+    }
+}
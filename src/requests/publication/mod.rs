@@ -1 +1,7 @@
+pub(crate) mod comment;
+mod list_feed;
 pub(crate) mod post;
+pub(crate) mod rate;
+pub(crate) mod reaction;
+
+pub(crate) use list_feed::ListFeedRequest;
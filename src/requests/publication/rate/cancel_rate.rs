@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, Request};
+use crate::models::publication::CancelRateError;
+use crate::{Client, Result};
+
+#[derive(Serialize)]
+pub(crate) struct CancelRateRequest {
+    #[serde(rename = "unitId")]
+    id: u64,
+}
+impl CancelRateRequest {
+    pub(crate) fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
+
+impl Request for CancelRateRequest {
+    type Response = EmptyResponse;
+    type Error = CancelRateError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_request("RPublicationsKarmaCancel", self, Vec::new())
+            .await
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{InfallibleRequest, Request};
+use crate::models::publication::RateEntry;
+use crate::requests::raw::publication::RawRate;
+use crate::{Client, Error, Result, RootError};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    rates: Vec<RawRate>,
+}
+
+impl TryFrom<Response> for Vec<RateEntry> {
+    type Error = Error;
+
+    fn try_from(value: Response) -> Result<Self> {
+        value.rates.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct ListRatesRequest {
+    #[serde(rename = "unitId")]
+    id: u64,
+    offset: usize,
+}
+impl ListRatesRequest {
+    pub(crate) const PAGE_SIZE: usize = 20;
+
+    pub(crate) fn new(id: u64, offset: usize) -> Self {
+        Self { id, offset }
+    }
+}
+
+impl Request for ListRatesRequest {
+    type Response = Response;
+    type Error = InfallibleRequest<RootError>;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request("RPublicationsKarmaGetAll", self, Vec::new())
+            .await
+    }
+}
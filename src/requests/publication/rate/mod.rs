@@ -0,0 +1,7 @@
+mod cancel_rate;
+mod list_rates;
+mod rate_publication;
+
+pub(crate) use cancel_rate::CancelRateRequest;
+pub(crate) use list_rates::ListRatesRequest;
+pub(crate) use rate_publication::RatePublicationRequest;
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Request;
+use crate::models::publication::{Rate, RateError};
+use crate::{Client, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct Response {
+    #[serde(rename = "myKarmaCount")]
+    karma: f64,
+}
+
+impl From<Response> for f64 {
+    fn from(value: Response) -> Self {
+        value.karma / 100.0
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct RatePublicationRequest {
+    #[serde(rename = "unitId")]
+    id: u64,
+    #[serde(rename = "up")]
+    is_up: bool,
+    #[serde(rename = "anon")]
+    is_anonymous: bool,
+}
+impl RatePublicationRequest {
+    pub(crate) fn new(id: u64, rate: Rate, is_anonymous: bool) -> Self {
+        Self {
+            id,
+            is_up: rate == Rate::Up,
+            is_anonymous,
+        }
+    }
+}
+
+impl Request for RatePublicationRequest {
+    type Response = Response;
+    type Error = RateError;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<Response> {
+        client
+            .send_request("RPublicationsKarmaAdd", self, Vec::new())
+            .await
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::client::{EmptyResponse, InfallibleRequest, Request};
+use crate::{Client, Result, RootError};
+
+#[derive(Serialize)]
+pub(crate) struct AddReactionRequest {
+    #[serde(rename = "unitId")]
+    id: u64,
+    #[serde(rename = "reactionIndex")]
+    index: i64,
+}
+impl AddReactionRequest {
+    pub(crate) fn new(id: u64, index: i64) -> Self {
+        Self { id, index }
+    }
+}
+
+impl Request for AddReactionRequest {
+    type Response = EmptyResponse;
+    type Error = InfallibleRequest<RootError>;
+
+    const IS_RETRYABLE: bool = false;
+
+    async fn send_request(&self, client: &Client) -> Result<EmptyResponse> {
+        client
+            .send_request("RPublicationsReactionAdd", self, Vec::new())
+            .await
+    }
+}
@@ -0,0 +1,5 @@
+mod add_reaction;
+mod remove_reaction;
+
+pub(crate) use add_reaction::AddReactionRequest;
+pub(crate) use remove_reaction::RemoveReactionRequest;
@@ -2,6 +2,7 @@ use serde::Deserialize;
 
 use crate::models::Badge;
 use crate::requests::raw::RawImageRef;
+use crate::{Error, Result};
 
 #[derive(Deserialize)]
 pub(crate) struct RawBadge {
@@ -11,11 +12,18 @@ pub(crate) struct RawBadge {
     pub image: RawImageRef,
 }
 
-impl From<RawBadge> for Badge {
-    fn from(value: RawBadge) -> Self {
-        Self {
+impl TryFrom<RawBadge> for Badge {
+    type Error = Error;
+
+    fn try_from(value: RawBadge) -> Result<Self> {
+        #[expect(deprecated)]
+        Ok(Self {
+            id: u64::try_from(value.index).map_err(|error| {
+                Error::ConversionError(format!("failed to convert badge id into u64 ({error})"))
+            })?,
             index: value.index,
             image: value.image.into(),
-        }
+            ..Default::default()
+        })
     }
 }
@@ -70,7 +70,11 @@ impl TryFrom<RawAccount> for Account {
                 #[expect(clippy::cast_sign_loss)]
                 |color| color as u32,
             ),
-            active_badge: value.customization.active_badge.map(Into::into),
+            active_badge: value
+                .customization
+                .active_badge
+                .map(TryInto::try_into)
+                .transpose()?,
         })
     }
 }
@@ -2,7 +2,7 @@ use serde::Deserialize;
 
 use crate::models::ImageRef;
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 pub(crate) struct RawImageRef {
     #[serde(rename = "i")]
     pub id: u64,
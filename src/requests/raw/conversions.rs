@@ -14,3 +14,11 @@ pub(super) fn naive_time_from_parts(hour: u32, minute: u32) -> Result<NaiveTime>
         ))
     })
 }
+
+// The server sends empty strings in place of missing text
+pub(super) fn optional_text(text: String) -> Option<String> {
+    match text.as_str() {
+        "" => None,
+        _ => Some(text),
+    }
+}
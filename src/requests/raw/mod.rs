@@ -5,6 +5,8 @@ mod conversions;
 pub(super) mod error;
 pub(super) mod fandom;
 pub(super) mod initial_data;
+#[cfg(feature = "fcm")]
+pub(super) mod notification;
 pub(super) mod profile;
 pub(super) mod publication;
 pub(super) mod settings;
@@ -20,8 +22,10 @@ pub(crate) use error::RawRootError;
 pub(super) use error::RawUnavailableError;
 pub(super) use fandom::RawFandom;
 pub(super) use initial_data::RawInitialData;
+#[cfg(feature = "fcm")]
+pub(crate) use notification::{RawPushEvent, RawPushPayload};
 pub(super) use profile::{RawGender, RawLink};
 pub(super) use publication::{
     AnyRawPublication, RawChatMessage, RawComment, RawPost, RawPostTag, RawPublication, RawReaction,
 };
-pub(super) use settings::RawSettings;
+pub(super) use settings::{RawFeedKind, RawSettings};
@@ -0,0 +1,172 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::models::notification::PushEvent;
+use crate::models::{Account, Publication};
+use crate::requests::raw::conversions::optional_text;
+use crate::requests::raw::publication::RawKind;
+use crate::requests::raw::{RawChatMessage, RawGender, RawImageRef, RawPublication};
+use crate::{Error, Result};
+
+// The FCM envelope the notification itself is wrapped into
+#[derive(Deserialize)]
+pub(crate) struct RawPushPayload {
+    pub data: RawPushData,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawPushData {
+    pub my_data: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawPushEvent {
+    #[serde(rename = "J_N_TYPE")]
+    pub kind: i64,
+    #[serde(flatten)]
+    pub data: Value,
+}
+
+#[derive(Deserialize)]
+struct RawPushAccount {
+    #[serde(rename = "accountId")]
+    id: u64,
+    #[serde(rename = "accountName")]
+    name: String,
+    #[serde(rename = "accountImage")]
+    avatar: RawImageRef,
+    #[serde(rename = "accountSex")]
+    gender: RawGender,
+}
+
+impl TryFrom<RawPushAccount> for Account {
+    type Error = Error;
+
+    fn try_from(value: RawPushAccount) -> Result<Self> {
+        Ok(Self {
+            id: value.id,
+            name: value.name,
+            avatar: value.avatar.into(),
+            gender: value.gender.try_into()?,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct RawChatMessageEvent {
+    #[serde(rename = "publicationChatMessage")]
+    message: RawPublication<RawChatMessage>,
+}
+
+#[derive(Deserialize)]
+struct RawCommentEvent {
+    #[serde(rename = "unitId")]
+    publication_id: u64,
+    #[serde(rename = "unitType")]
+    publication_kind: RawKind,
+    #[serde(rename = "commentId")]
+    comment_id: u64,
+    #[serde(rename = "commentText")]
+    text: String,
+    #[serde(rename = "parentCommentText", default)]
+    parent_text: String,
+    #[serde(flatten)]
+    author: RawPushAccount,
+}
+
+#[derive(Deserialize)]
+struct RawRateEvent {
+    #[serde(rename = "unitId")]
+    publication_id: u64,
+    #[serde(rename = "unitType")]
+    publication_kind: RawKind,
+    #[serde(rename = "karmaCount")]
+    karma: f64,
+    #[serde(rename = "anon", default)]
+    is_anonymous: bool,
+    #[serde(flatten)]
+    rater: RawPushAccount,
+}
+
+#[derive(Deserialize)]
+struct RawFollowEvent {
+    #[serde(flatten)]
+    follower: RawPushAccount,
+}
+
+#[derive(Deserialize)]
+struct RawAchievementEvent {
+    #[serde(rename = "achiIndex")]
+    index: i64,
+    #[serde(rename = "achiLvl")]
+    level: i64,
+}
+
+impl TryFrom<RawPushEvent> for PushEvent {
+    type Error = Error;
+
+    fn try_from(value: RawPushEvent) -> Result<Self> {
+        Ok(match value.kind {
+            1 | 3 => {
+                let event = serde_json::from_value::<RawCommentEvent>(value.data)?;
+                let (publication_id, publication_kind, comment_id, text, author) = (
+                    event.publication_id,
+                    event.publication_kind.into(),
+                    event.comment_id,
+                    optional_text(event.text),
+                    event.author.try_into()?,
+                );
+
+                if value.kind == 1 {
+                    PushEvent::Comment {
+                        publication_id,
+                        publication_kind,
+                        comment_id,
+                        text,
+                        author,
+                    }
+                } else {
+                    PushEvent::Reply {
+                        publication_id,
+                        publication_kind,
+                        comment_id,
+                        text,
+                        parent_text: optional_text(event.parent_text),
+                        author,
+                    }
+                }
+            }
+            2 => {
+                let event = serde_json::from_value::<RawRateEvent>(value.data)?;
+                PushEvent::Rate {
+                    publication_id: event.publication_id,
+                    publication_kind: event.publication_kind.into(),
+                    karma: event.karma / 100.0,
+                    rater: match (event.is_anonymous, event.rater.id) {
+                        (true, _) | (_, 0) => None,
+                        _ => Some(event.rater.try_into()?),
+                    },
+                }
+            }
+            4 => {
+                let event = serde_json::from_value::<RawAchievementEvent>(value.data)?;
+                PushEvent::Achievement {
+                    index: event.index,
+                    level: event.level,
+                }
+            }
+            11 => {
+                let event = serde_json::from_value::<RawChatMessageEvent>(value.data)?;
+                PushEvent::ChatMessage(Box::new(Publication::try_from(event.message)?))
+            }
+            14 => {
+                let event = serde_json::from_value::<RawFollowEvent>(value.data)?;
+                PushEvent::Follow {
+                    follower: event.follower.try_into()?,
+                }
+            }
+            other => PushEvent::Unknown(other),
+        })
+    }
+}
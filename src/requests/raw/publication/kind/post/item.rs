@@ -0,0 +1,272 @@
+use std::result::Result as StdResult;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::models::publication::{PostItem, PostTableCell};
+use crate::requests::raw::RawImageRef;
+use crate::requests::raw::conversions::optional_text;
+use crate::{Error, Result};
+
+// Text items of this size are displayed as titles
+const TEXT_SIZE_TITLE: i64 = 1;
+
+pub(crate) enum RawItemKind {
+    Text,
+    Image,
+    Images,
+    Link,
+    Quote,
+    Spoiler,
+    Poll,
+    Video,
+    Table,
+    Download,
+    CampfireObject,
+    LinkImage,
+    Code,
+    Unknown(i64),
+}
+
+impl<'de> Deserialize<'de> for RawItemKind {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match i64::deserialize(deserializer)? {
+            1 => RawItemKind::Text,
+            2 => RawItemKind::Image,
+            3 => RawItemKind::Images,
+            4 => RawItemKind::Link,
+            5 => RawItemKind::Quote,
+            6 => RawItemKind::Spoiler,
+            7 => RawItemKind::Poll,
+            8 => RawItemKind::Video,
+            9 => RawItemKind::Table,
+            10 => RawItemKind::Download,
+            11 => RawItemKind::CampfireObject,
+            13 => RawItemKind::LinkImage,
+            14 => RawItemKind::Code,
+            other => RawItemKind::Unknown(other),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawItem {
+    #[serde(rename = "J_PAGE_TYPE")]
+    pub kind: RawItemKind,
+    #[serde(flatten)]
+    pub data: Value,
+}
+
+#[derive(Deserialize)]
+struct RawText {
+    #[serde(rename = "J_TEXT")]
+    text: String,
+    #[serde(default)]
+    size: i64,
+}
+
+#[derive(Deserialize)]
+struct RawImage {
+    image: RawImageRef,
+    // Missing or zeroed out for still images
+    #[serde(default)]
+    gif: RawImageRef,
+}
+
+#[derive(Deserialize)]
+struct RawImages {
+    images: Vec<RawImageRef>,
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct RawLink {
+    name: String,
+    link: String,
+    image: Option<RawImageRef>,
+}
+
+#[derive(Deserialize)]
+struct RawQuote {
+    author: String,
+    #[serde(rename = "J_TEXT")]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpoiler {
+    name: String,
+    #[serde(rename = "count")]
+    items_count: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPoll {
+    #[serde(rename = "pollingId")]
+    id: u64,
+    title: String,
+    options: Vec<String>,
+    min_level: f64,
+    min_karma: f64,
+    min_days: u64,
+}
+
+#[derive(Deserialize)]
+struct RawVideo {
+    #[serde(rename = "videoId")]
+    id: String,
+    image: RawImageRef,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTable {
+    rows_count: u64,
+    columns_count: u64,
+    cells: Vec<RawTableCell>,
+}
+
+#[derive(Deserialize)]
+struct RawTableCell {
+    #[serde(rename = "rowIndex")]
+    row: u64,
+    #[serde(rename = "columnIndex")]
+    column: u64,
+    #[serde(rename = "J_TEXT")]
+    text: String,
+    image: Option<RawImageRef>,
+}
+
+#[derive(Deserialize)]
+struct RawDownload {
+    #[serde(rename = "resourceId")]
+    resource_id: u64,
+    title: String,
+    size: u64,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+    language: String,
+}
+
+#[derive(Deserialize)]
+struct RawCampfireObject {
+    link: String,
+}
+
+impl TryFrom<RawItem> for PostItem {
+    type Error = Error;
+
+    #[expect(clippy::too_many_lines)]
+    fn try_from(value: RawItem) -> Result<Self> {
+        Ok(match value.kind {
+            RawItemKind::Text => {
+                let item = serde_json::from_value::<RawText>(value.data)?;
+                match item.size {
+                    TEXT_SIZE_TITLE => PostItem::Title(item.text),
+                    _ => PostItem::Text(item.text),
+                }
+            }
+            RawItemKind::Image => {
+                let item = serde_json::from_value::<RawImage>(value.data)?;
+                match item.gif.id {
+                    0 => PostItem::Image(item.image.into()),
+                    _ => PostItem::Gif {
+                        first_frame: item.image.into(),
+                        animated: item.gif.into(),
+                    },
+                }
+            }
+            RawItemKind::Images => {
+                let item = serde_json::from_value::<RawImages>(value.data)?;
+                PostItem::Images {
+                    images: item.images.into_iter().map(Into::into).collect(),
+                    title: optional_text(item.title),
+                }
+            }
+            RawItemKind::Link | RawItemKind::LinkImage => {
+                let item = serde_json::from_value::<RawLink>(value.data)?;
+                PostItem::Link {
+                    name: item.name,
+                    uri: item.link,
+                    image: item.image.and_then(Into::into),
+                }
+            }
+            RawItemKind::Quote => {
+                let item = serde_json::from_value::<RawQuote>(value.data)?;
+                PostItem::Quote {
+                    author: optional_text(item.author),
+                    text: item.text,
+                }
+            }
+            RawItemKind::Spoiler => {
+                let item = serde_json::from_value::<RawSpoiler>(value.data)?;
+                PostItem::Spoiler {
+                    name: item.name,
+                    items_count: item.items_count,
+                }
+            }
+            RawItemKind::Poll => {
+                let item = serde_json::from_value::<RawPoll>(value.data)?;
+                PostItem::Poll {
+                    id: item.id,
+                    title: optional_text(item.title),
+                    options: item.options,
+                    min_level: item.min_level / 100.0,
+                    min_karma: item.min_karma / 100.0,
+                    min_days: item.min_days,
+                }
+            }
+            RawItemKind::Video => {
+                let item = serde_json::from_value::<RawVideo>(value.data)?;
+                PostItem::Video {
+                    id: item.id,
+                    preview: item.image.into(),
+                }
+            }
+            RawItemKind::Table => {
+                let item = serde_json::from_value::<RawTable>(value.data)?;
+                PostItem::Table {
+                    rows_count: item.rows_count,
+                    columns_count: item.columns_count,
+                    cells: item
+                        .cells
+                        .into_iter()
+                        .map(|cell| PostTableCell {
+                            row: cell.row,
+                            column: cell.column,
+                            text: optional_text(cell.text),
+                            image: cell.image.and_then(Into::into),
+                        })
+                        .collect(),
+                }
+            }
+            RawItemKind::Download => {
+                let item = serde_json::from_value::<RawDownload>(value.data)?;
+                PostItem::Download {
+                    resource_id: item.resource_id,
+                    title: item.title,
+                    size: item.size,
+                }
+            }
+            RawItemKind::Code => {
+                let item = serde_json::from_value::<RawCode>(value.data)?;
+                PostItem::Code {
+                    code: item.code,
+                    language: optional_text(item.language),
+                }
+            }
+            RawItemKind::CampfireObject => {
+                let item = serde_json::from_value::<RawCampfireObject>(value.data)?;
+                PostItem::CampfireObject { link: item.link }
+            }
+            RawItemKind::Unknown(unknown) => PostItem::Unknown(unknown),
+        })
+    }
+}
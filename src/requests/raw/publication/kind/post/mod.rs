@@ -1,6 +1,8 @@
 mod favorites_folder;
+mod item;
 
 pub(crate) use favorites_folder::RawFavoritesFolder;
+pub(crate) use item::RawItem;
 use serde::Deserialize;
 
 use crate::models::Post;
@@ -10,8 +12,8 @@ use crate::{Error, Result};
 
 #[derive(Deserialize)]
 pub(crate) struct InnerData {
-    // #[serde(rename = "J_PAGES")]
-    // pub items: Vec<RawItem>,
+    #[serde(rename = "J_PAGES")]
+    pub items: Vec<RawItem>,
     // pub title: Option<String>,
 }
 
@@ -62,6 +64,12 @@ impl TryFrom<RawPost> for Post {
             fandom: value.fandom.try_into()?,
             author: value.author.try_into()?,
             category: value.category.into(),
+            items: value
+                .inner
+                .items
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
             best_comment: value.best_comment.map(TryInto::try_into).transpose()?,
             karma: value.karma / 100.0,
             my_karma: match value.my_karma {
@@ -1,10 +1,12 @@
 mod kind;
+mod rate;
 mod reaction;
 mod status;
 
 use std::marker::PhantomData;
 
 pub(crate) use kind::*;
+pub(crate) use rate::RawRate;
 pub(crate) use reaction::RawReaction;
 use serde::Deserialize;
 use serde_json::Value;
@@ -30,6 +32,8 @@ pub(crate) struct RawPublication<T: RawPublishable = AnyRawPublication> {
     pub kind: RawKind,
     pub status: RawStatus,
     pub hotness: f32,
+    #[serde(default)]
+    pub reactions: Vec<RawReaction>,
     #[serde(flatten)]
     pub additional_data: Value,
     #[serde(skip)]
@@ -50,6 +54,7 @@ where
             created_at: timestamp_from_millis(value.created_at)?,
             status: Option::<Status>::try_from(value.status)?,
             hotness: value.hotness,
+            reactions: value.reactions.into_iter().map(Into::into).collect(),
         })
     }
 }
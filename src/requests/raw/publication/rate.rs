@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use crate::models::publication::RateEntry;
+use crate::requests::raw::RawAccount;
+use crate::requests::raw::conversions::timestamp_from_millis;
+use crate::{Error, Result};
+
+#[derive(Deserialize)]
+pub(crate) struct RawRate {
+    #[serde(rename = "unitId")]
+    pub publication_id: u64,
+    #[serde(rename = "from")]
+    pub rater: RawAccount,
+    #[serde(rename = "karmaCount")]
+    pub karma: f64,
+    #[serde(rename = "date")]
+    pub rated_at: i64,
+    #[serde(rename = "anon")]
+    pub is_anonymous: bool,
+}
+
+impl TryFrom<RawRate> for RateEntry {
+    type Error = Error;
+
+    fn try_from(value: RawRate) -> Result<Self> {
+        Ok(Self {
+            publication_id: value.publication_id,
+            rater: value.rater.try_into()?,
+            karma: value.karma / 100.0,
+            rated_at: timestamp_from_millis(value.rated_at)?,
+            is_anonymous: value.is_anonymous,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use bonfire::models::{Account, Badge, Profile};
+use futures_util::TryStreamExt as _;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_list_badges() {
+    let (mock, client) = common::setup_single("account/badges/list.json");
+    let badges = Account::new(207_506)
+        .list_badges(&client)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(badges.len(), 2);
+    assert_eq!(badges[0].id, 11);
+    assert_eq!(badges[0].name, "Early bird");
+    assert_eq!(badges[1].description, "Published 100 posts");
+    assert!(badges[1].created_at.is_some());
+    assert_eq!(badges[1].image.id, 400_012);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_details() {
+    let (mock, client) = common::setup_single("account/badges/details.json");
+    let badge = Badge {
+        id: 12,
+        ..Default::default()
+    };
+    let details = badge.details(&client).await.unwrap();
+
+    assert_eq!(details.badge.name, "Writer");
+    assert_eq!(details.fandom_id, Some(42));
+    assert!(details.link.is_none());
+    assert_eq!(details.owner_id, 207_506);
+    assert_eq!(details.owner_name, "TestUser");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_set_badge_shelf() {
+    let (mock, client) = common::setup_single("account/badges/set_shelf.json");
+    let shelf = Profile::set_badge_shelf(&client, &[12, 11]).await.unwrap();
+
+    let ids = shelf.iter().map(|badge| badge.id).collect::<Vec<_>>();
+    assert_eq!(ids, [12, 11]);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_set_badge_shelf_visible() {
+    let (mock, client) = common::setup_single("account/badges/set_shelf_visible.json");
+    let shelf = Profile::set_badge_shelf_visible(&client, false)
+        .await
+        .unwrap();
+
+    assert!(shelf.is_empty());
+    mock.assert();
+}
@@ -0,0 +1,21 @@
+use bonfire::models::Account;
+use futures_util::TryStreamExt as _;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_list_rates() {
+    let (mock, client) = common::setup_single("publication/list_rates.json");
+    let rates = Account::new(207_506)
+        .list_rates(&client, 0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = rates
+        .iter()
+        .map(|rate| rate.publication_id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [5_512_345, 5_512_345]);
+    mock.assert();
+}
@@ -1,4 +1,6 @@
+mod badges;
 #[path = "../common/mod.rs"]
 mod common;
 mod get_by_id;
 mod get_by_name;
+mod list_rates;
@@ -1,3 +1,9 @@
+mod change;
 #[path = "../common/mod.rs"]
 mod common;
 mod login;
+mod oauth;
+mod refresh;
+mod register;
+mod session;
+mod store;
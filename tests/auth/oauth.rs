@@ -0,0 +1,53 @@
+use bonfire::Error;
+use bonfire::models::auth::{OAuthGrant, RevokeOAuthGrantError};
+use futures_util::TryStreamExt as _;
+
+use crate::common;
+
+fn grant() -> OAuthGrant {
+    OAuthGrant {
+        id: "g_1002".to_owned(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_list_oauth_grants() {
+    let (mock, client) = common::setup_single("auth/oauth_grants/list.json");
+    let grants = client
+        .list_oauth_grants(0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(grants.len(), 2);
+    assert_eq!(grants[0].client.name, "Bonfire Web");
+    assert!(grants[0].client.is_official);
+    assert!(grants[0].last_used_at.is_some());
+    assert_eq!(grants[1].id, "g_1002");
+    assert!(grants[1].client.tos_url.is_none());
+    assert!(grants[1].last_used_at.is_none());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_revoke() {
+    let (mock, client) = common::setup_single("auth/oauth_grants/revoke.json");
+    grant().revoke(&client).await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_revoke_not_found() {
+    let (mock, client) = common::setup_single("auth/oauth_grants/not_found.json");
+    let error = grant().revoke(&client).await.unwrap_err();
+
+    let Error::RequestError(error_box) = error else {
+        panic!("expected request error, got {error:?}");
+    };
+    assert!(matches!(
+        *error_box.downcast::<RevokeOAuthGrantError>().unwrap(),
+        RevokeOAuthGrantError::NotFound
+    ));
+    mock.assert();
+}
@@ -0,0 +1,74 @@
+use bonfire::Error;
+use bonfire::models::auth::{Session, TerminateSessionError};
+
+use crate::common;
+
+fn assert_error(error: Error, expected: &TerminateSessionError) {
+    let Error::RequestError(error_box) = error else {
+        panic!("expected request error, got {error:?}");
+    };
+    let error = error_box.downcast::<TerminateSessionError>().unwrap();
+    assert_eq!(error.to_string(), expected.to_string());
+}
+
+#[tokio::test]
+async fn test_list_sessions() {
+    let (mock, client) = common::setup_single("auth/account_security/success.json");
+    let sessions = client.list_sessions().await.unwrap();
+
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].id, "54277");
+    assert!(sessions[0].is_current);
+    assert_eq!(sessions[1].user_agent, "bot/1.0");
+    assert!(!sessions[1].is_current);
+    assert_eq!(sessions[1].last_active_at.timestamp(), 1_767_636_164);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_linked_logins() {
+    let (mock, client) = common::setup_single("auth/account_security/success.json");
+    let linked_logins = client.get_linked_logins().await.unwrap();
+
+    assert!(linked_logins.is_google_linked);
+    assert!(!linked_logins.is_firebase_linked);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_terminate() {
+    let (mock, client) = common::setup_single("auth/terminate_session/success.json");
+    let session = Session {
+        id: "51002".to_owned(),
+        ..Default::default()
+    };
+    session.terminate(&client).await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_terminate_not_found() {
+    let (mock, client) = common::setup_single("auth/terminate_session/not_found.json");
+    let session = Session {
+        id: "1".to_owned(),
+        ..Default::default()
+    };
+    let error = session.terminate(&client).await.unwrap_err();
+
+    assert_error(error, &TerminateSessionError::NotFound);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_terminate_current() {
+    let (mock, client) = common::setup_none();
+    let session = Session {
+        id: "54277".to_owned(),
+        is_current: true,
+        ..Default::default()
+    };
+    let error = session.terminate(&client).await.unwrap_err();
+
+    assert_error(error, &TerminateSessionError::CurrentSession);
+    mock.assert_calls(0);
+}
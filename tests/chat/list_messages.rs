@@ -0,0 +1,47 @@
+use bonfire::models::chat::MessageAnchor;
+use bonfire::models::publication::ChatMessageContent;
+use bonfire::models::{Chat, ChatTag};
+use futures_util::TryStreamExt as _;
+
+use crate::common;
+
+const TAG: ChatTag = ChatTag::Group { id: 1 };
+
+#[tokio::test]
+async fn test_backwards() {
+    let (mock, client) = common::setup_single("chat/list_messages.json");
+    let messages = Chat::new(TAG)
+        .list_messages(&client, MessageAnchor::Newest)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = messages
+        .iter()
+        .map(|message| message.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [1002, 1001]);
+    assert_eq!(messages[0].kind.text.as_deref(), Some("Hello, world!"));
+    assert!(matches!(
+        &messages[1].kind.content,
+        ChatMessageContent::RenameEvent { new_name, .. } if new_name == "New name"
+    ));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_forwards() {
+    let (mock, client) = common::setup_single("chat/list_messages.json");
+    let messages = Chat::new(TAG)
+        .list_messages(&client, MessageAnchor::After(1000))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = messages
+        .iter()
+        .map(|message| message.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [1001, 1002]);
+    mock.assert();
+}
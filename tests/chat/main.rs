@@ -0,0 +1,4 @@
+#[path = "../common/mod.rs"]
+mod common;
+mod list_messages;
+mod send_message;
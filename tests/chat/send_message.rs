@@ -0,0 +1,85 @@
+use std::mem;
+
+use bonfire::models::chat::{MessageBuilder, SendMessageError};
+use bonfire::models::publication::ChatMessageContent;
+use bonfire::models::{Chat, ChatTag};
+use bonfire::{Error, Result};
+
+use crate::common;
+
+const TAG: ChatTag = ChatTag::Group { id: 1 };
+
+fn assert_error<T: std::fmt::Debug>(result: Result<T>, send_error: SendMessageError) {
+    match result {
+        Err(Error::RequestError(error_box)) => {
+            let error = error_box.downcast::<SendMessageError>().unwrap();
+            assert!(
+                mem::discriminant(&*error) == mem::discriminant(&send_error),
+                "expected {send_error:?}, got {error:?}"
+            );
+        }
+        Err(error) => panic!("expected request error, got {error:?}"),
+        Ok(value) => panic!("expected request error, got {value:?}"),
+    };
+}
+
+#[tokio::test]
+async fn test_empty() {
+    let (mock, client) = common::setup_none();
+    let result = Chat::new(TAG)
+        .send_message(&client, &MessageBuilder::new())
+        .await;
+
+    assert_error(result, SendMessageError::InvalidTextLength);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_text_too_long() {
+    let (mock, client) = common::setup_none();
+    let text = "a".repeat(2001);
+    let result = Chat::new(TAG)
+        .send_message(&client, &MessageBuilder::new().text(&text))
+        .await;
+
+    assert_error(result, SendMessageError::InvalidTextLength);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_too_many_images() {
+    let (mock, client) = common::setup_none();
+    let image = [0; 16];
+    let images = vec![&image[..]; ChatMessageContent::IMAGES_MAX_COUNT + 1];
+    let result = Chat::new(TAG)
+        .send_message(&client, &MessageBuilder::new().images(images))
+        .await;
+
+    assert_error(result, SendMessageError::TooManyImages);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_image_too_large() {
+    let (mock, client) = common::setup_none();
+    let image = vec![0; ChatMessageContent::IMAGE_MAX_SIZE + 1];
+    let result = Chat::new(TAG)
+        .send_message(&client, &MessageBuilder::new().image(&image))
+        .await;
+
+    assert_error(result, SendMessageError::ImageTooLarge);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_gif_too_large() {
+    let (mock, client) = common::setup_none();
+    let first_frame = [0; 16];
+    let animated = vec![0; ChatMessageContent::GIF_MAX_SIZE + 1];
+    let result = Chat::new(TAG)
+        .send_message(&client, &MessageBuilder::new().gif(&first_frame, &animated))
+        .await;
+
+    assert_error(result, SendMessageError::GifTooLarge);
+    mock.assert_calls(0);
+}
@@ -0,0 +1,10 @@
+#[cfg(feature = "cassette")]
+mod cassette;
+#[path = "../common/mod.rs"]
+mod common;
+mod proxy;
+mod rate_limiter;
+mod retry;
+mod timeout;
+mod transport;
+mod untyped;
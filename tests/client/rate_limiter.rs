@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use bonfire::client::RateLimiter;
+use bonfire::models::Account;
+use bonfire::{Client, Error};
+use governor::Quota;
+use http::StatusCode;
+use nanoid::nanoid;
+use nonzero_ext::nonzero;
+
+use crate::common;
+
+fn setup_with_limiter(rate_limiter: RateLimiter) -> (Client, String) {
+    let endpoint = "/".to_owned() + &nanoid!();
+    let client = Client::builder()
+        .root_uri(common::MOCK_SERVER.url(&endpoint))
+        .melior_uri(common::MOCK_SERVER.url(&endpoint))
+        .rate_limiter(rate_limiter)
+        .build();
+
+    (client, endpoint)
+}
+
+// Allows a single request per server
+fn single_request_limiter() -> RateLimiter {
+    let quota = Quota::per_hour(nonzero!(1u32));
+    RateLimiter::new(quota, quota).blocking(false)
+}
+
+fn assert_rate_limited<T>(result: Result<T, Error>) {
+    match result {
+        Err(Error::RateLimited { retry_after }) => assert!(!retry_after.is_zero()),
+        Err(error) => panic!("expected rate limited error, got {error:?}"),
+        Ok(_) => panic!("expected rate limited error, got a response"),
+    }
+}
+
+#[tokio::test]
+async fn test_non_blocking() {
+    let (client, endpoint) = setup_with_limiter(single_request_limiter());
+    let mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(endpoint);
+        then.body(common::load_fixture("account/get_account.json"));
+    });
+
+    Account::get_by_id(&client, 1).await.unwrap();
+    assert_rate_limited(Account::get_by_id(&client, 1).await);
+    mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_shared() {
+    let limiter = single_request_limiter();
+    let (first_client, first_endpoint) = setup_with_limiter(limiter.clone());
+    let (second_client, second_endpoint) = setup_with_limiter(limiter);
+    let first_mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(first_endpoint);
+        then.body(common::load_fixture("account/get_account.json"));
+    });
+    let second_mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(second_endpoint);
+        then.body(common::load_fixture("account/get_account.json"));
+    });
+
+    Account::get_by_id(&first_client, 1).await.unwrap();
+    assert_rate_limited(Account::get_by_id(&second_client, 1).await);
+    first_mock.assert_calls(1);
+    second_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_separate_budgets() {
+    let (client, endpoint) = setup_with_limiter(single_request_limiter());
+    let root_mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(&endpoint).body_includes("J_REQUEST_NAME");
+        then.body(common::load_fixture("account/get_account.json"));
+    });
+    let melior_mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(&endpoint).body_includes("AccountSecurityQuery");
+        then.body(common::load_fixture("auth/account_security/success.json"));
+    });
+
+    Account::get_by_id(&client, 1).await.unwrap();
+    client.list_sessions().await.unwrap();
+    root_mock.assert_calls(1);
+    melior_mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_pause_after_too_many_requests() {
+    let quota = Quota::per_minute(nonzero!(60u32));
+    let (client, endpoint) = setup_with_limiter(RateLimiter::new(quota, quota).blocking(false));
+    let mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(endpoint);
+        then.status(429).header("retry-after", "60");
+    });
+
+    assert!(matches!(
+        Account::get_by_id(&client, 1).await.unwrap_err(),
+        Error::UnsuccessfulResponse(StatusCode::TOO_MANY_REQUESTS)
+    ));
+    match Account::get_by_id(&client, 1).await {
+        Err(Error::RateLimited { retry_after }) => {
+            assert!(retry_after > Duration::from_secs(50));
+        }
+        result => panic!("expected rate limited error, got {result:?}"),
+    }
+    mock.assert_calls(1);
+}
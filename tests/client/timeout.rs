@@ -0,0 +1,112 @@
+use std::io::{Read as _, Write as _};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use bonfire::client::{RetryPolicy, TimeoutPhase};
+use bonfire::models::Account;
+use bonfire::{Client, ClientBuilder, Error};
+use httpmock::Mock;
+use nanoid::nanoid;
+use tokio::net::TcpSocket;
+
+use crate::common;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+fn setup_delayed(builder: ClientBuilder) -> (Mock<'static>, Client) {
+    let endpoint = "/".to_owned() + &nanoid!();
+    let client = builder
+        .root_uri(common::MOCK_SERVER.url(&endpoint))
+        .melior_uri(common::MOCK_SERVER.url(&endpoint))
+        .build();
+    let mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(endpoint);
+        then.delay(Duration::from_secs(2))
+            .body(common::load_fixture("account/get_account.json"));
+    });
+
+    (mock, client)
+}
+
+#[tokio::test]
+async fn test_request_timeout() {
+    let (mock, client) = setup_delayed(Client::builder().request_timeout(TIMEOUT));
+    let result = Account::get_by_id(&client, 1).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::Timeout {
+            phase: TimeoutPhase::Request
+        }
+    ));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_request_timeout_override() {
+    let (mock, client) = setup_delayed(Client::builder());
+    let result = Account::get_by_id(&client.with_timeout(TIMEOUT), 1).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::Timeout {
+            phase: TimeoutPhase::Request
+        }
+    ));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_read_timeout() {
+    // Sends the headers right away, but never finishes the body
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let _ = stream.read(&mut [0; 4096]);
+        let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 100\r\n\r\n{");
+        thread::sleep(Duration::from_secs(2));
+    });
+
+    let client = Client::builder()
+        .root_uri(format!("http://{address}/"))
+        .read_timeout(TIMEOUT)
+        .build();
+    let result = Account::get_by_id(&client, 1).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::Timeout {
+            phase: TimeoutPhase::BodyRead
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_connect_timeout() {
+    // Once the accept queue of a listener that never accepts is full, new connection attempts are
+    // left unanswered
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.bind("127.0.0.1:0".parse().unwrap()).unwrap();
+    let listener = socket.listen(1).unwrap();
+    let address = listener.local_addr().unwrap();
+    let mut streams = Vec::new();
+    while let Ok(stream) = TcpStream::connect_timeout(&address, TIMEOUT) {
+        streams.push(stream);
+    }
+
+    let client = Client::builder()
+        .root_uri(format!("http://{address}/"))
+        .connect_timeout(TIMEOUT)
+        .retry_policy(RetryPolicy::new().max_attempts(1))
+        .build();
+    let result = Account::get_by_id(&client, 1).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::Timeout {
+            phase: TimeoutPhase::Connect
+        }
+    ));
+}
@@ -0,0 +1,55 @@
+use bonfire::client::MemoryTransport;
+use bonfire::models::Account;
+use bonfire::{Client, Error};
+use bytes::Bytes;
+use http::{Method, Response, StatusCode};
+
+use crate::common;
+
+const ROOT_URI: &str = "http://root.test/";
+
+fn setup(transport: &MemoryTransport) -> Client {
+    Client::builder()
+        .root_uri(ROOT_URI)
+        .transport(transport.clone())
+        .build()
+}
+
+#[tokio::test]
+async fn test_memory_transport() {
+    let transport = MemoryTransport::new(|_| {
+        Response::new(Bytes::from(common::load_fixture(
+            "account/get_account.json",
+        )))
+    });
+    let client = setup(&transport);
+    let result = Account::get_by_id(&client, 207506).await;
+
+    assert_eq!(result.unwrap().id, 207506);
+
+    let requests = transport.take_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), Method::POST);
+    assert_eq!(requests[0].uri(), ROOT_URI);
+    assert!(
+        String::from_utf8_lossy(requests[0].body()).contains("\"J_REQUEST_NAME\":\"RAccountsGet\"")
+    );
+    assert!(transport.take_requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_memory_transport_unsuccessful() {
+    let transport = MemoryTransport::new(|_| {
+        let mut response = Response::new(Bytes::new());
+        *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+        response
+    });
+    let client = setup(&transport);
+    let result = Account::get_by_id(&client, 207506).await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::UnsuccessfulResponse(StatusCode::SERVICE_UNAVAILABLE)
+    ));
+    assert_eq!(transport.take_requests().len(), 1);
+}
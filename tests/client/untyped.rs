@@ -0,0 +1,79 @@
+use bonfire::client::MemoryTransport;
+use bonfire::{Client, Error, RootError, UnavailableError};
+use bytes::Bytes;
+use http::Response;
+use serde_json::json;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_root_call() {
+    let transport = MemoryTransport::new(|_| {
+        Response::new(Bytes::from(common::load_fixture(
+            "account/get_account.json",
+        )))
+    });
+    let client = Client::builder().transport(transport.clone()).build();
+    let result = client
+        .root_call("RAccountsGet", json!({"accountId": 207506}), vec![b"data"])
+        .await;
+
+    assert_eq!(result.unwrap()["account"]["J_NAME"], "TestUser");
+
+    let requests = transport.take_requests();
+    let body = String::from_utf8_lossy(requests[0].body());
+    assert!(body.contains("\"J_REQUEST_NAME\":\"RAccountsGet\""));
+    assert!(body.contains("\"accountId\":207506"));
+    assert!(body.contains("\"dataOutput\":[4]"));
+    assert!(body.ends_with("data"));
+}
+
+#[tokio::test]
+async fn test_root_call_error() {
+    let (mock, client) = common::setup_single("error/not_found.json");
+    let result = client
+        .root_call("RAccountsGet", json!({"accountId": 999999999}), Vec::new())
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::RootError(RootError::Unavailable(UnavailableError::NotFound))
+    ));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_root_call_invalid_params() {
+    let (mock, client) = common::setup_none();
+    let result = client
+        .root_call("RAccountsGet", json!([1]), Vec::new())
+        .await;
+
+    assert!(matches!(result.unwrap_err(), Error::ConversionError(_)));
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_graphql() {
+    let (mock, client) = common::setup_single("auth/account_security/success.json");
+    let result = client
+        .graphql("MeQuery", "query MeQuery { me { email } }", json!({}))
+        .await;
+
+    assert_eq!(result.unwrap()["me"]["email"], "test@example.com");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_graphql_error() {
+    let (mock, client) = common::setup_single("auth/account_security/token_expired.json");
+    let result = client
+        .graphql("MeQuery", "query MeQuery { me { email } }", json!({}))
+        .await;
+
+    let Error::MeliorError(error) = result.unwrap_err() else {
+        panic!("expected a melior error");
+    };
+    assert_eq!(error.message, "TokenExpired: Access token has expired");
+    mock.assert();
+}
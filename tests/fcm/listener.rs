@@ -0,0 +1,335 @@
+use bonfire::client::{FcmError, Proxy, PushListener};
+use bonfire::models::FcmAndroidRegistration;
+use bonfire::{Client, Error};
+use futures_util::StreamExt as _;
+use prost::Message;
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use tokio::net::{TcpListener, TcpStream};
+
+const ANDROID_ID: u64 = 5_123_456_789;
+const PERSISTENT_ID: &str = "0:1774695616642%7031b2e6f9fd7ecd";
+const OTHER_PERSISTENT_ID: &str = "0:1774695617254%7031b2e6f9fd7ecd";
+
+#[derive(Message)]
+struct LoginRequest {
+    #[prost(string, required, tag = "3")]
+    user: String,
+    #[prost(string, required, tag = "5")]
+    auth_token: String,
+    #[prost(string, repeated, tag = "10")]
+    received_persistent_id: Vec<String>,
+}
+
+#[derive(Message)]
+struct HeartbeatPing {
+    #[prost(int32, optional, tag = "2")]
+    last_stream_id_received: Option<i32>,
+}
+
+#[derive(Message)]
+struct ErrorInfo {
+    #[prost(int32, required, tag = "1")]
+    code: i32,
+    #[prost(string, optional, tag = "2")]
+    message: Option<String>,
+}
+
+#[derive(Message)]
+struct LoginResponse {
+    #[prost(string, required, tag = "1")]
+    id: String,
+    #[prost(message, optional, tag = "3")]
+    error: Option<ErrorInfo>,
+}
+
+#[derive(Message)]
+struct AppData {
+    #[prost(string, required, tag = "1")]
+    key: String,
+    #[prost(string, required, tag = "2")]
+    value: String,
+}
+
+#[derive(Message)]
+struct DataMessageStanza {
+    #[prost(string, required, tag = "3")]
+    from: String,
+    #[prost(string, required, tag = "5")]
+    category: String,
+    #[prost(message, repeated, tag = "7")]
+    app_data: Vec<AppData>,
+    #[prost(string, optional, tag = "9")]
+    persistent_id: Option<String>,
+    #[prost(bytes = "vec", optional, tag = "21")]
+    raw_data: Option<Vec<u8>>,
+}
+
+#[derive(Message)]
+struct Extension {
+    #[prost(int32, required, tag = "1")]
+    id: i32,
+    #[prost(bytes = "vec", required, tag = "2")]
+    data: Vec<u8>,
+}
+
+#[derive(Message)]
+struct IqStanza {
+    #[prost(int32, required, tag = "2")]
+    r#type: i32,
+    #[prost(message, optional, tag = "7")]
+    extension: Option<Extension>,
+}
+
+#[derive(Message)]
+struct SelectiveAck {
+    #[prost(string, repeated, tag = "1")]
+    id: Vec<String>,
+}
+
+pub(crate) fn android() -> FcmAndroidRegistration {
+    FcmAndroidRegistration {
+        installation_auth_token: String::new(),
+        android_id: ANDROID_ID,
+        security_token: 42,
+        gcm_token: String::new(),
+    }
+}
+
+async fn setup_server() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    (listener, endpoint)
+}
+
+async fn read_frame(stream: &mut TcpStream) -> (u8, Vec<u8>) {
+    let tag = stream.read_u8().await.unwrap();
+    let mut length = 0;
+    for shift in (0..35).step_by(7) {
+        let byte = stream.read_u8().await.unwrap();
+        length |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    let mut message = vec![0; length];
+    stream.read_exact(&mut message).await.unwrap();
+    (tag, message)
+}
+
+async fn write_frame(stream: &mut TcpStream, tag: u8, message: &impl Message) {
+    let mut frame = vec![tag];
+    message.encode_length_delimited(&mut frame).unwrap();
+    stream.write_all(&frame).await.unwrap();
+}
+
+// Accepts a connection, checks the version and login request, and answers with `response`
+async fn accept_login(
+    listener: &TcpListener,
+    response: &LoginResponse,
+) -> (TcpStream, LoginRequest) {
+    let (stream, _) = listener.accept().await.unwrap();
+    answer_login(stream, response).await
+}
+
+async fn answer_login(
+    mut stream: TcpStream,
+    response: &LoginResponse,
+) -> (TcpStream, LoginRequest) {
+    assert_eq!(stream.read_u8().await.unwrap(), 41);
+    let (tag, message) = read_frame(&mut stream).await;
+    assert_eq!(tag, 2);
+    let request = LoginRequest::decode(&message[..]).unwrap();
+    assert_eq!(request.user, ANDROID_ID.to_string());
+    assert_eq!(request.auth_token, "42");
+
+    stream.write_u8(41).await.unwrap();
+    write_frame(&mut stream, 3, response).await;
+    (stream, request)
+}
+
+fn login_success() -> LoginResponse {
+    LoginResponse {
+        id: "login".to_owned(),
+        error: None,
+    }
+}
+
+fn data_message(persistent_id: Option<&str>) -> DataMessageStanza {
+    DataMessageStanza {
+        from: "778141366343".to_owned(),
+        category: "org.chromium.linux".to_owned(),
+        app_data: Vec::new(),
+        persistent_id: persistent_id.map(str::to_owned),
+        raw_data: None,
+    }
+}
+
+// Reads a SelectiveAck and returns the acknowledged IDs
+async fn read_ack(stream: &mut TcpStream) -> Vec<String> {
+    let (tag, message) = read_frame(stream).await;
+    assert_eq!(tag, 7);
+    let extension = IqStanza::decode(&message[..]).unwrap().extension.unwrap();
+    assert_eq!(extension.id, 12);
+    SelectiveAck::decode(&extension.data[..]).unwrap().id
+}
+
+#[tokio::test]
+async fn test_data_message() {
+    let (listener, endpoint) = setup_server().await;
+    let mut messages = Box::pin(PushListener::new(android()).endpoint(endpoint).listen());
+
+    let server = async {
+        let (mut stream, _) = accept_login(&listener, &login_success()).await;
+
+        write_frame(
+            &mut stream,
+            8,
+            &DataMessageStanza {
+                from: "778141366343".to_owned(),
+                category: "org.chromium.linux".to_owned(),
+                app_data: vec![AppData {
+                    key: "crypto-key".to_owned(),
+                    value: "dh=key".to_owned(),
+                }],
+                persistent_id: Some(PERSISTENT_ID.to_owned()),
+                raw_data: Some(vec![1, 2, 3]),
+            },
+        )
+        .await;
+
+        // The message must be acknowledged with a SelectiveAck
+        assert_eq!(read_ack(&mut stream).await, [PERSISTENT_ID]);
+    };
+
+    let (message, ()) = tokio::join!(messages.next(), server);
+    let message = message.unwrap().unwrap();
+    assert_eq!(message.persistent_id.as_deref(), Some(PERSISTENT_ID));
+    assert_eq!(message.from, "778141366343");
+    assert_eq!(message.category, "org.chromium.linux");
+    assert_eq!(message.app_data["crypto-key"], "dh=key");
+    assert_eq!(message.raw_data, Some(vec![1, 2, 3]));
+}
+
+#[tokio::test]
+async fn test_unconfirmed_acks() {
+    let (listener, endpoint) = setup_server().await;
+    let _messages = Box::pin(PushListener::new(android()).endpoint(endpoint).listen());
+
+    // The client's stream IDs: 1 is the login request, 2 the first acknowledgement, 3 the
+    // heartbeat acknowledgement and 4 the second acknowledgement
+    let (mut stream, _) = accept_login(&listener, &login_success()).await;
+    write_frame(&mut stream, 8, &data_message(Some(PERSISTENT_ID))).await;
+    assert_eq!(read_ack(&mut stream).await, [PERSISTENT_ID]);
+
+    let ping = HeartbeatPing {
+        last_stream_id_received: Some(2),
+    };
+    write_frame(&mut stream, 0, &ping).await;
+    assert_eq!(read_frame(&mut stream).await.0, 1);
+
+    write_frame(&mut stream, 8, &data_message(Some(OTHER_PERSISTENT_ID))).await;
+    assert_eq!(read_ack(&mut stream).await, [OTHER_PERSISTENT_ID]);
+    drop(stream);
+
+    // Only the acknowledgement the server hasn't confirmed is reported again
+    let (_stream, request) = accept_login(&listener, &login_success()).await;
+    assert_eq!(request.received_persistent_id, [OTHER_PERSISTENT_ID]);
+}
+
+#[tokio::test]
+async fn test_slow_consumer() {
+    let (listener, endpoint) = setup_server().await;
+    let mut messages = Box::pin(PushListener::new(android()).endpoint(endpoint).listen());
+    let (mut stream, _) = accept_login(&listener, &login_success()).await;
+
+    // One more message than the channel can hold while nothing is consumed
+    let ids = (0..65)
+        .map(|i| format!("0:{i}%7031b2e6f9fd7ecd"))
+        .collect::<Vec<_>>();
+    for id in &ids {
+        write_frame(&mut stream, 8, &data_message(Some(id))).await;
+    }
+    for id in &ids[..64] {
+        assert_eq!(read_ack(&mut stream).await, [id.as_str()]);
+    }
+
+    // Heartbeats are still answered while the last message waits for room in the channel
+    write_frame(&mut stream, 0, &HeartbeatPing::default()).await;
+    assert_eq!(read_frame(&mut stream).await.0, 1);
+
+    // The last message is only acknowledged once it has been delivered
+    let message = messages.next().await.unwrap().unwrap();
+    assert_eq!(message.persistent_id.as_ref(), Some(&ids[0]));
+    assert_eq!(read_ack(&mut stream).await, [ids[64].as_str()]);
+}
+
+#[tokio::test]
+async fn test_login_error() {
+    let (listener, endpoint) = setup_server().await;
+    let mut messages = Box::pin(PushListener::new(android()).endpoint(endpoint).listen());
+
+    let response = LoginResponse {
+        id: "login".to_owned(),
+        error: Some(ErrorInfo {
+            code: 401,
+            message: Some("unauthorized".to_owned()),
+        }),
+    };
+    let server = accept_login(&listener, &response);
+
+    let (result, _) = tokio::join!(messages.next(), server);
+    assert!(matches!(
+        result.unwrap().unwrap_err(),
+        Error::FcmError(FcmError::McsLoginError { code: 401, .. })
+    ));
+    assert!(messages.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_reconnect() {
+    let (listener, endpoint) = setup_server().await;
+    let mut messages = Box::pin(PushListener::new(android()).endpoint(endpoint).listen());
+
+    let server = async {
+        // The first connection is dropped right after the login
+        drop(accept_login(&listener, &login_success()).await);
+
+        let (mut stream, _) = accept_login(&listener, &login_success()).await;
+        write_frame(&mut stream, 8, &data_message(None)).await;
+        stream
+    };
+
+    let (message, _stream) = tokio::join!(messages.next(), server);
+    assert_eq!(message.unwrap().unwrap().from, "778141366343");
+}
+
+#[tokio::test]
+async fn test_client_proxy() {
+    let (listener, address) = setup_server().await;
+    let client = Client::builder().proxy(Proxy::new(address)).build();
+    // The host can't be resolved, so the connection only succeeds through the proxy
+    let mut messages = Box::pin(
+        client
+            .push_listener(android())
+            .endpoint("http://mcs.test:5228")
+            .listen(),
+    );
+
+    let server = async {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut connect = Vec::new();
+        while !connect.ends_with(b"\r\n\r\n") {
+            connect.push(stream.read_u8().await.unwrap());
+        }
+        assert!(connect.starts_with(b"CONNECT mcs.test:5228 HTTP/1.1\r\n"));
+        stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n").await.unwrap();
+
+        let (mut stream, _) = answer_login(stream, &login_success()).await;
+        write_frame(&mut stream, 8, &data_message(None)).await;
+        stream
+    };
+
+    let (message, _stream) = tokio::join!(messages.next(), server);
+    assert_eq!(message.unwrap().unwrap().from, "778141366343");
+}
@@ -0,0 +1,6 @@
+#![cfg(feature = "fcm")]
+
+#[path = "../common/mod.rs"]
+mod common;
+mod listener;
+mod push_event;
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use base64::Engine as _;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use bonfire::Error;
+use bonfire::client::FcmError;
+use bonfire::models::notification::PushEvent;
+use bonfire::models::publication::Kind;
+use bonfire::models::{FcmCredentials, FcmMessage};
+use serde_json::json;
+
+use crate::common;
+
+fn credentials() -> FcmCredentials {
+    let (key_pair, auth_secret) = ece::generate_keypair_and_auth_secret().unwrap();
+    let components = key_pair.raw_components().unwrap();
+
+    FcmCredentials {
+        token: "token".to_owned(),
+        public_key: BASE64_URL_SAFE_NO_PAD.encode(components.public_key()),
+        private_key: BASE64_URL_SAFE_NO_PAD.encode(components.private_key()),
+        auth_secret: BASE64_URL_SAFE_NO_PAD.encode(auth_secret),
+        android: crate::listener::android(),
+    }
+}
+
+fn message(app_data: HashMap<String, String>, raw_data: Option<Vec<u8>>) -> FcmMessage {
+    FcmMessage {
+        persistent_id: None,
+        from: "778141366343".to_owned(),
+        category: "org.chromium.linux".to_owned(),
+        app_data,
+        raw_data,
+        sent_at: None,
+    }
+}
+
+// Wraps a notification into the envelope the server sends it in
+fn payload(fixture_path: &'static str) -> Vec<u8> {
+    let notification = String::from_utf8(common::load_fixture(fixture_path)).unwrap();
+    serde_json::to_vec(&json!({ "data": { "my_data": notification } })).unwrap()
+}
+
+fn decode(value: &str) -> Vec<u8> {
+    BASE64_URL_SAFE_NO_PAD.decode(value).unwrap()
+}
+
+#[test]
+fn test_aes128gcm() {
+    let credentials = credentials();
+    let data = ece::encrypt(
+        &decode(&credentials.public_key),
+        &decode(&credentials.auth_secret),
+        &payload("fcm/chat_message.json"),
+    )
+    .unwrap();
+
+    let event = credentials
+        .decrypt(&message(HashMap::new(), Some(data)))
+        .unwrap();
+    let PushEvent::ChatMessage(message) = event else {
+        panic!("expected a chat message, got {event:?}");
+    };
+    assert_eq!(message.id, 1002);
+    assert_eq!(message.kind.text.as_deref(), Some("Hello, world!"));
+}
+
+#[test]
+fn test_aesgcm() {
+    let credentials = credentials();
+    let block = ece::legacy::encrypt_aesgcm(
+        &decode(&credentials.public_key),
+        &decode(&credentials.auth_secret),
+        &payload("fcm/reply.json"),
+    )
+    .unwrap();
+
+    let app_data = block
+        .headers(None)
+        .into_iter()
+        .map(|(key, value)| (key.to_lowercase(), value))
+        .collect();
+    let event = credentials
+        .decrypt(&message(app_data, Some(decode(&block.body()))))
+        .unwrap();
+
+    let PushEvent::Reply {
+        publication_id,
+        publication_kind,
+        comment_id,
+        text,
+        parent_text,
+        author,
+    } = event
+    else {
+        panic!("expected a reply, got {event:?}");
+    };
+    assert_eq!(publication_id, 5_512_345);
+    assert_eq!(publication_kind, Kind::Post);
+    assert_eq!(comment_id, 5_512_400);
+    assert_eq!(text.as_deref(), Some("Thanks!"));
+    assert_eq!(parent_text.as_deref(), Some("Nice post"));
+    assert_eq!(author.id, 207_506);
+    assert_eq!(author.name, "TestUser");
+    assert_eq!(author.avatar.unwrap().id, 289_130);
+}
+
+#[test]
+fn test_unencrypted() {
+    let app_data = HashMap::from([("my_data".to_owned(), json!({ "J_N_TYPE": 999 }).to_string())]);
+
+    let event = credentials().decrypt(&message(app_data, None)).unwrap();
+    assert!(matches!(event, PushEvent::Unknown(999)));
+}
+
+#[test]
+fn test_no_payload() {
+    let error = credentials()
+        .decrypt(&message(HashMap::new(), None))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::FcmError(FcmError::InvalidPushMessage(_))
+    ));
+}
@@ -0,0 +1,139 @@
+use std::mem;
+
+use bonfire::models::publication::{
+    CommentBuilder, CommentContent, CreateCommentError, EditCommentError,
+};
+use bonfire::models::{Comment, Post, Publication};
+use bonfire::{Error, Result};
+
+use crate::common;
+
+fn post() -> Publication<Post> {
+    Publication {
+        id: 5_512_345,
+        ..Default::default()
+    }
+}
+
+fn comment() -> Publication<Comment> {
+    Publication {
+        id: 2001,
+        ..Default::default()
+    }
+}
+
+fn assert_error<T: std::fmt::Debug, E: std::error::Error + 'static>(
+    result: Result<T>,
+    expected: E,
+) {
+    match result {
+        Err(Error::RequestError(error_box)) => {
+            let error = error_box.downcast::<E>().unwrap();
+            assert!(
+                mem::discriminant(&*error) == mem::discriminant(&expected),
+                "expected {expected:?}, got {error:?}"
+            );
+        }
+        Err(error) => panic!("expected request error, got {error:?}"),
+        Ok(value) => panic!("expected request error, got {value:?}"),
+    };
+}
+
+#[tokio::test]
+async fn test_comment() {
+    let (mock, client) = common::setup_single("publication/create_comment.json");
+    let comment = post()
+        .comment(
+            &client,
+            &CommentBuilder::new().text("Second").reply_to(2001),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(comment.id, 2002);
+    assert_eq!(comment.kind.text.as_deref(), Some("Second"));
+    assert_eq!(comment.kind.reply_to.unwrap().id, 2001);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_comment_empty() {
+    let (mock, client) = common::setup_none();
+    let result = post().comment(&client, &CommentBuilder::new()).await;
+
+    assert_error(result, CreateCommentError::InvalidTextLength);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_comment_text_too_long() {
+    let (mock, client) = common::setup_none();
+    let text = "a".repeat(Comment::TEXT_LENGTH_RANGE.end() + 1);
+    let result = post()
+        .comment(&client, &CommentBuilder::new().text(&text))
+        .await;
+
+    assert_error(result, CreateCommentError::InvalidTextLength);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_comment_too_many_images() {
+    let (mock, client) = common::setup_none();
+    let image = [0; 16];
+    let images = vec![&image[..]; CommentContent::IMAGES_MAX_COUNT + 1];
+    let result = post()
+        .comment(&client, &CommentBuilder::new().images(images))
+        .await;
+
+    assert_error(result, CreateCommentError::TooManyImages);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_comment_blocked() {
+    let (mock, client) = common::setup_single("error/black_list.json");
+    let result = post()
+        .comment(&client, &CommentBuilder::new().sticker(42))
+        .await;
+
+    assert_error(result, CreateCommentError::Blocked);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_comment_bad_text_size() {
+    let (mock, client) = common::setup_single("error/bad_text_size.json");
+    let result = post()
+        .comment(&client, &CommentBuilder::new().text("Second"))
+        .await;
+
+    assert_error(result, CreateCommentError::InvalidTextLength);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_edit() {
+    let (mock, client) = common::setup_single("publication/edit_comment.json");
+    let comment = comment().edit(&client, "First! (edited)").await.unwrap();
+
+    assert_eq!(comment.kind.text.as_deref(), Some("First! (edited)"));
+    assert!(comment.kind.is_edited);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_edit_empty() {
+    let (mock, client) = common::setup_none();
+    let result = comment().edit(&client, "").await;
+
+    assert_error(result, EditCommentError::InvalidTextLength);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let (mock, client) = common::setup_single("publication/remove_comment.json");
+    comment().delete(&client).await.unwrap();
+    mock.assert();
+}
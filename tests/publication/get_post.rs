@@ -0,0 +1,81 @@
+use bonfire::models::publication::PostItem;
+use bonfire::models::{Post, Publication};
+
+use crate::common;
+
+#[tokio::test]
+async fn test_items() {
+    let (mock, client) = common::setup_single("publication/get_post.json");
+    let (post, tags) = Publication::<Post>::get_by_id(&client, 5_512_345)
+        .await
+        .unwrap();
+
+    assert_eq!(post.id, 5_512_345);
+    assert!(tags.is_empty());
+
+    let items = &post.kind.items;
+    assert_eq!(items.len(), 17);
+    assert!(matches!(&items[0], PostItem::Title(text) if text == "Release notes"));
+    assert!(matches!(&items[1], PostItem::Text(text) if text == "Version 2.0 is out."));
+    assert!(matches!(&items[2], PostItem::Image(image) if image.id == 300_001));
+    assert!(matches!(
+        &items[3],
+        PostItem::Gif { first_frame, animated } if first_frame.id == 300_002 && animated.id == 300_003
+    ));
+    assert!(matches!(
+        &items[4],
+        PostItem::Images { images, title: None } if images.len() == 2
+    ));
+    assert!(matches!(
+        &items[5],
+        PostItem::Link { name, uri, image: None }
+            if name == "Changelog" && uri == "https://example.com/changelog"
+    ));
+    assert!(matches!(
+        &items[6],
+        PostItem::Quote { author: Some(author), text } if author == "Someone" && text == "Ship it."
+    ));
+    assert!(matches!(
+        &items[7],
+        PostItem::Spoiler { items_count: 2, .. }
+    ));
+    assert!(matches!(
+        &items[8],
+        PostItem::Poll { id: 77, options, min_level, min_days: 7, .. }
+            if options.len() == 2 && (*min_level - 2.0).abs() < f64::EPSILON
+    ));
+    assert!(matches!(
+        &items[9],
+        PostItem::Video { id, preview: Some(_) } if id == "dQw4w9WgXcQ"
+    ));
+
+    let PostItem::Table {
+        rows_count: 2,
+        columns_count: 2,
+        cells,
+    } = &items[10]
+    else {
+        panic!("expected a table, got {:?}", items[10]);
+    };
+    assert_eq!(cells[0].text.as_deref(), Some("Key"));
+    assert!(cells[1].text.is_none());
+    assert_eq!(cells[1].image.as_ref().unwrap().id, 300_007);
+
+    assert!(matches!(
+        &items[11],
+        PostItem::Download {
+            resource_id: 300_008,
+            size: 1024,
+            ..
+        }
+    ));
+    assert!(matches!(&items[12], PostItem::CampfireObject { link } if link == "@fandom_42"));
+    assert!(matches!(
+        &items[13],
+        PostItem::Code { language: Some(language), .. } if language == "rust"
+    ));
+    assert!(matches!(&items[14], PostItem::Unknown(99)));
+    assert!(matches!(&items[15], PostItem::Text(text) if text == "A page without a size."));
+    assert!(matches!(&items[16], PostItem::Image(image) if image.id == 300_009));
+    mock.assert();
+}
@@ -0,0 +1,143 @@
+use bonfire::Client;
+use bonfire::models::publication::{CommentRef, CommentThread};
+use bonfire::models::{ChatMessage, Comment, Post, Publication};
+use futures_util::TryStreamExt as _;
+use nanoid::nanoid;
+
+use crate::common;
+
+fn post() -> Publication<Post> {
+    Publication {
+        id: 5_512_345,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_list_comments() {
+    let (mock, client) = common::setup_single("publication/list_comments.json");
+    let comments = post()
+        .list_comments(&client, 0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = comments
+        .iter()
+        .map(|comment| comment.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [2001, 2002, 2003, 2004]);
+    assert!(
+        comments
+            .iter()
+            .all(|comment| comment.kind.parent_id == 5_512_345)
+    );
+    assert_eq!(comments[1].kind.reply_to.as_ref().unwrap().id, 2001);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_comments_parent_kind() {
+    let endpoint = "/".to_owned() + &nanoid!();
+    let client = Client::builder()
+        .root_uri(common::MOCK_SERVER.url(&endpoint))
+        .build();
+    let mock = common::MOCK_SERVER.mock(|when, then| {
+        when.path(&endpoint)
+            .body_includes(r#""unitId":7700001"#)
+            .body_includes(r#""unitType":8"#);
+        then.body(common::load_fixture("publication/list_comments.json"));
+    });
+    let message = Publication::<ChatMessage> {
+        id: 7_700_001,
+        ..Default::default()
+    };
+    message
+        .list_comments(&client, 0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_threads() {
+    let (mock, client) = common::setup_single("publication/list_comments.json");
+    let comments = post()
+        .list_comments(&client, 0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let threads = CommentThread::build(comments);
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].comment.id, 2001);
+    assert_eq!(threads[0].replies.len(), 1);
+    assert_eq!(threads[0].replies[0].comment.id, 2002);
+    assert_eq!(threads[0].replies[0].replies[0].comment.id, 2004);
+    assert_eq!(threads[1].comment.id, 2003);
+    assert!(threads[1].replies.is_empty());
+    mock.assert();
+}
+
+#[test]
+fn test_orphaned_reply() {
+    let comments =
+        [(3001, None), (3002, Some(3000)), (3003, Some(3001))].map(|(id, reply_to)| Publication {
+            id,
+            kind: Comment {
+                parent_id: 5_512_345,
+                reply_to: reply_to.map(|id| CommentRef {
+                    id,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    // The reply to a missing comment becomes a root of its own
+    let threads = CommentThread::build(comments);
+    let ids = threads
+        .iter()
+        .map(|thread| thread.comment.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [3001, 3002]);
+    assert_eq!(threads[0].replies[0].comment.id, 3003);
+}
+
+#[test]
+fn test_reply_order() {
+    let comments = [
+        (4001, None),
+        (4002, Some(4001)),
+        (4003, Some(4001)),
+        (4004, Some(4002)),
+        (4005, Some(4001)),
+        (4006, Some(4002)),
+    ]
+    .map(|(id, reply_to)| Publication {
+        id,
+        kind: Comment {
+            parent_id: 5_512_345,
+            reply_to: reply_to.map(|id| CommentRef {
+                id,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    let threads = CommentThread::build(comments);
+    let ids = |threads: &[CommentThread]| {
+        threads
+            .iter()
+            .map(|thread| thread.comment.id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&threads), [4001]);
+    assert_eq!(ids(&threads[0].replies), [4002, 4003, 4005]);
+    assert_eq!(ids(&threads[0].replies[0].replies), [4004, 4006]);
+}
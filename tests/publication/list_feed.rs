@@ -0,0 +1,115 @@
+use bonfire::Client;
+use bonfire::models::settings::{Feed, FeedFilter, FeedKind};
+use futures_util::TryStreamExt as _;
+use httpmock::HttpMockRequest;
+use nanoid::nanoid;
+use serde_json::{Value, json};
+
+use crate::common;
+
+#[tokio::test]
+async fn test_list_feed() {
+    let (mock, client) = common::setup_single("publication/list_feed.json");
+    let publications = Feed::default()
+        .stream(&client, FeedKind::All, None, None)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = publications
+        .iter()
+        .map(|publication| publication.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [6001, 6002, 6003]);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_feed_filtered() {
+    let (mock, client) = common::setup_single("publication/list_feed.json");
+    let filter = FeedFilter {
+        hide_nsfw_posts: true,
+        blocked_fandom_ids: vec![12],
+    };
+    let publications = Feed::default()
+        .stream(&client, FeedKind::AllWithFollows, None, Some(&filter))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = publications
+        .iter()
+        .map(|publication| publication.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [6001]);
+    mock.assert();
+}
+
+fn offset_date(request: &HttpMockRequest) -> i64 {
+    let (length, body) = request.body_ref().split_at(4);
+    let length = usize::try_from(u32::from_be_bytes(length.try_into().unwrap())).unwrap();
+    let body = serde_json::from_slice::<Value>(&body[..length]).unwrap();
+    body["offsetDate"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn test_list_feed_same_date() {
+    // 25 publications, five of them created at the same millisecond across the page boundary
+    let template =
+        serde_json::from_slice::<Value>(&common::load_fixture("publication/list_feed.json"))
+            .unwrap()["J_RESPONSE"]["units"][0]
+            .clone();
+    let units = (0_i64..25)
+        .map(|index| {
+            let mut unit = template.clone();
+            let minutes = match index {
+                18..=22 => 18,
+                _ => index,
+            };
+            unit["id"] = json!(7000 + index);
+            unit["dateCreate"] = json!(1_774_695_600_000 - 60_000 * minutes);
+            unit
+        })
+        .collect::<Vec<_>>();
+
+    // Serves the publications created before the offset date, like the server does. Offset dates
+    // between the same two creation dates all get the same page
+    let endpoint = "/".to_owned() + &nanoid!();
+    let mut dates = units
+        .iter()
+        .map(|unit| unit["dateCreate"].as_i64().unwrap())
+        .collect::<Vec<_>>();
+    dates.dedup();
+    let mut upper = i64::MAX;
+    for date in dates.into_iter().chain([i64::MIN]) {
+        let page = units
+            .iter()
+            .filter(|unit| unit["dateCreate"].as_i64().unwrap() <= date)
+            .take(20)
+            .collect::<Vec<_>>();
+        let is_first = upper == i64::MAX;
+        common::MOCK_SERVER.mock(|when, then| {
+            when.path(&endpoint).is_true(move |request| {
+                let offset_date = offset_date(request);
+                (is_first && offset_date == 0)
+                    || (offset_date != 0 && offset_date > date && offset_date <= upper)
+            });
+            then.json_body(json!({"J_STATUS": "J_STATUS_OK", "J_RESPONSE": {"units": page}}));
+        });
+        upper = date;
+    }
+    let client = Client::builder()
+        .root_uri(common::MOCK_SERVER.url(&endpoint))
+        .build();
+    let publications = Feed::default()
+        .stream(&client, FeedKind::All, None, None)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = publications
+        .iter()
+        .map(|publication| publication.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, (7000..7025).collect::<Vec<_>>());
+}
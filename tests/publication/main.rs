@@ -0,0 +1,8 @@
+mod comment;
+#[path = "../common/mod.rs"]
+mod common;
+mod get_post;
+mod list_comments;
+mod list_feed;
+mod rate;
+mod reaction;
@@ -0,0 +1,55 @@
+use bonfire::Error;
+use bonfire::models::publication::{Rate, RateError};
+use bonfire::models::{Post, Publication};
+use futures_util::TryStreamExt as _;
+
+use crate::common;
+
+fn post() -> Publication<Post> {
+    Publication {
+        id: 5_512_345,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_rate() {
+    let (mock, client) = common::setup_single("publication/rate.json");
+    let karma = post().rate(&client, Rate::Up, false).await.unwrap();
+
+    assert!((karma - 3.5).abs() < f64::EPSILON);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_rate_karma_cap() {
+    let (mock, client) = common::setup_single("error/karma_cap.json");
+    let result = post().rate(&client, Rate::Down, true).await;
+
+    match result {
+        Err(Error::RequestError(error)) => assert!(matches!(
+            error.downcast::<RateError>().unwrap().as_ref(),
+            RateError::KarmaCapReached
+        )),
+        other => panic!("expected request error, got {other:?}"),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_rates() {
+    let (mock, client) = common::setup_single("publication/list_rates.json");
+    let rates = post()
+        .list_rates(&client, 0)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates[0].rater.as_ref().unwrap().id, 207_506);
+    assert!((rates[0].karma - 3.5).abs() < f64::EPSILON);
+    assert!(rates[1].is_anonymous);
+    assert!(rates[1].rater.is_none());
+    assert!((rates[1].karma + 1.0).abs() < f64::EPSILON);
+    mock.assert();
+}